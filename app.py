@@ -14,5 +14,11 @@ async def index():
         html_content = await f.read()
     return HTMLResponse(content=html_content)
 
+@app.get("/editor", response_class=HTMLResponse)
+async def editor():
+    async with aiofiles.open(os.path.join("templates", "editor.html")) as f:
+        html_content = await f.read()
+    return HTMLResponse(content=html_content)
+
 if __name__ == "__main__":
     uvicorn.run(app, host="0.0.0.0", port=8000)
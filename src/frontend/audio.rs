@@ -0,0 +1,83 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AudioBuffer, AudioContext};
+
+use crate::game::enums::sound::Sound;
+
+use crate::Game;
+
+pub struct Audio {
+    audio_context: AudioContext,
+    sounds: Vec<(Sound, AudioBuffer)>,
+}
+
+impl Audio {
+    pub async fn new() -> Result<Self, JsValue> {
+        let audio_context = AudioContext::new()?;
+        let sounds = Audio::load_sound_files(&audio_context).await?;
+        Ok(Audio {
+            audio_context,
+            sounds,
+        })
+    }
+
+    fn get_file_name(sound: Sound) -> &'static str {
+        match sound {
+            Sound::ClaimDiamond => "../static/sound/ClaimDiamond.mp3",
+            Sound::DiamondFallOnSomething => "../static/sound/DiamondFallOnSomething.mp3",
+            Sound::PlayerMove => "../static/sound/PlayerMove.mp3",
+            Sound::RockFallOnSomethingOrPushed => "../static/sound/RockFallOnSomethingOrPushed.mp3",
+            Sound::WalkOnDirt => "../static/sound/WalkOnDirt.mp3",
+            Sound::MagicWall => "../static/sound/MagicWall.wav",
+            Sound::TimeBonus => "../static/sound/TimeBonus.wav",
+            Sound::ExitOpen => "../static/sound/ExitOpen.wav",
+            Sound::Death => "../static/sound/Death.wav",
+        }
+    }
+
+    async fn load_sound_files(
+        audio_context: &AudioContext,
+    ) -> Result<Vec<(Sound, AudioBuffer)>, JsValue> {
+        let sounds = vec![
+            Sound::ClaimDiamond,
+            Sound::DiamondFallOnSomething,
+            Sound::PlayerMove,
+            Sound::RockFallOnSomethingOrPushed,
+            Sound::WalkOnDirt,
+            Sound::MagicWall,
+            Sound::TimeBonus,
+            Sound::ExitOpen,
+            Sound::Death,
+        ];
+        let mut buffers = Vec::new();
+        for sound in sounds {
+            let buffer = Audio::load_sound_file(Audio::get_file_name(sound), audio_context).await?;
+            buffers.push((sound, buffer));
+        }
+        Ok(buffers)
+    }
+
+    async fn load_sound_file(
+        path: &str,
+        audio_context: &AudioContext,
+    ) -> Result<AudioBuffer, JsValue> {
+        let resp = Game::load_file(path).await?;
+        let buffer = JsFuture::from(resp.array_buffer()?).await?;
+        let promise = audio_context.decode_audio_data(&buffer.into())?;
+        let audio_buffer = JsFuture::from(promise).await?;
+        Ok(audio_buffer.unchecked_into::<AudioBuffer>())
+    }
+
+    pub fn play(&self, sound: Sound) {
+        if let Some((_, audio_buffer)) = self.sounds.iter().find(|(s, _)| *s == sound) {
+            let source = self.audio_context.create_buffer_source().unwrap();
+            source.set_buffer(Some(audio_buffer));
+            source
+                .connect_with_audio_node(&self.audio_context.destination())
+                .unwrap();
+            source.set_loop(false);
+            source.start().unwrap();
+        }
+    }
+}
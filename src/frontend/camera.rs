@@ -0,0 +1,63 @@
+use crate::game::display::{scroller::Scroller, zone::Zone};
+
+#[derive(Debug)]
+pub struct Camera {
+    zones: Vec<Zone>,
+    zone: Zone,
+    scroller: Option<Scroller>,
+}
+
+impl Camera {
+    pub fn new(
+        width: i32,
+        height: i32,
+        canvas_width: i32,
+        canvas_height: i32,
+        player_position: (i32, i32),
+    ) -> Self {
+        let zones = Zone::from_map(width, height, canvas_width, canvas_height);
+        let zone = *Zone::get_current_zone(player_position.0, player_position.1, &zones)
+            .expect("No zone found for player");
+        Camera {
+            zones,
+            zone,
+            scroller: None,
+        }
+    }
+
+    pub fn update(&mut self, player_position: (i32, i32)) -> Option<Zone> {
+        let mut redraw = None;
+        if let Some(scroller) = &mut self.scroller {
+            if let Some(active_zone) = scroller.update() {
+                redraw = Some(active_zone);
+            } else {
+                self.scroller = None;
+                redraw = Some(self.zone);
+            }
+        }
+
+        if let Some(current_zone) =
+            Zone::get_current_zone(player_position.0, player_position.1, &self.zones)
+        {
+            if *current_zone != self.zone {
+                if let Some(scroller) = &self.scroller {
+                    if let Some(active_zone) = scroller.get_active_zone() {
+                        self.scroller = Some(Scroller::new(active_zone, *current_zone));
+                    }
+                } else {
+                    self.scroller = Some(Scroller::new(self.zone, *current_zone));
+                }
+                self.zone = *current_zone;
+            }
+        }
+        redraw
+    }
+
+    pub fn get_zone(&self) -> &Zone {
+        &self.zone
+    }
+
+    pub fn is_scrolling(&self) -> bool {
+        self.scroller.is_some()
+    }
+}
@@ -0,0 +1,57 @@
+use wasm_bindgen::JsCast;
+
+const STICK_THRESHOLD: f64 = 0.5;
+const BUTTONS: [(u32, &str); 8] = [
+    (0, "GamepadA"),
+    (1, "GamepadB"),
+    (8, "GamepadSelect"),
+    (9, "GamepadStart"),
+    (12, "GamepadUp"),
+    (13, "GamepadDown"),
+    (14, "GamepadLeft"),
+    (15, "GamepadRight"),
+];
+
+pub fn get_pressed_buttons() -> Vec<&'static str> {
+    let mut pressed = Vec::new();
+    let Some(window) = web_sys::window() else {
+        return pressed;
+    };
+    let Ok(gamepads) = window.navigator().get_gamepads() else {
+        return pressed;
+    };
+    for gamepad in gamepads.iter() {
+        let Ok(gamepad) = gamepad.dyn_into::<web_sys::Gamepad>() else {
+            continue;
+        };
+        if !gamepad.connected() {
+            continue;
+        }
+        let buttons = gamepad.buttons();
+        for (index, name) in BUTTONS {
+            let is_pressed = buttons
+                .get(index)
+                .dyn_into::<web_sys::GamepadButton>()
+                .is_ok_and(|button| button.pressed());
+            if is_pressed && !pressed.contains(&name) {
+                pressed.push(name);
+            }
+        }
+        let axes = gamepad.axes();
+        let (x, y) = (
+            axes.get(0).as_f64().unwrap_or(0.0),
+            axes.get(1).as_f64().unwrap_or(0.0),
+        );
+        for (active, name) in [
+            (y < -STICK_THRESHOLD, "GamepadUp"),
+            (y > STICK_THRESHOLD, "GamepadDown"),
+            (x < -STICK_THRESHOLD, "GamepadLeft"),
+            (x > STICK_THRESHOLD, "GamepadRight"),
+        ] {
+            if active && !pressed.contains(&name) {
+                pressed.push(name);
+            }
+        }
+    }
+    pressed
+}
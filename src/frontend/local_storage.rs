@@ -0,0 +1,26 @@
+use crate::game::interfaces::storage::Storage;
+
+pub struct LocalStorage {
+    storage: web_sys::Storage,
+}
+
+impl LocalStorage {
+    pub fn new() -> Option<Self> {
+        let storage = web_sys::window()?.local_storage().ok()??;
+        Some(LocalStorage { storage })
+    }
+}
+
+impl Storage for LocalStorage {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.storage.get_item(key).ok()?
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) {
+        let _ = self.storage.set_item(key, value);
+    }
+
+    fn remove_item(&mut self, key: &str) {
+        let _ = self.storage.remove_item(key);
+    }
+}
@@ -0,0 +1,315 @@
+use serde::Serialize;
+
+use crate::game::{
+    enums::{input_action::InputAction, movement::Movement},
+    input::InputBindings,
+    level::definition::LevelDefinition,
+    save::{LevelRecord, SaveManager, Settings},
+};
+
+const MAX_DIFFICULTY: u32 = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MenuScreen {
+    Title,
+    Main,
+    LevelSelect,
+    Options,
+    Controls,
+    HighScores,
+    Credits,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum MenuChoice {
+    Play { level: u32 },
+    Resume,
+    RandomCave,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MenuEntry {
+    Continue,
+    Play,
+    LevelSelect,
+    RandomCave,
+    Options,
+    HighScores,
+    Credits,
+    Level(u32),
+    Sound,
+    Difficulty,
+    Controls,
+    Binding(InputAction),
+    ResetControls,
+    Text,
+    Back,
+}
+
+pub struct Menu {
+    screen: MenuScreen,
+    selected: usize,
+    levels: Vec<(String, Option<LevelRecord>)>,
+    has_saved_game: bool,
+    settings: Settings,
+    rebinding: Option<InputAction>,
+    displaced: Option<InputAction>,
+}
+
+impl Menu {
+    pub fn new(levels: &[LevelDefinition], save: &SaveManager) -> Self {
+        let levels = levels
+            .iter()
+            .enumerate()
+            .take_while(|(index, _)| save.is_unlocked(*index as u32 + 1))
+            .map(|(index, level)| {
+                (
+                    level.get_header().get_name().to_uppercase(),
+                    save.get_record(index as u32 + 1),
+                )
+            })
+            .collect();
+        Menu {
+            screen: MenuScreen::Title,
+            selected: 0,
+            levels,
+            has_saved_game: save.get_saved_game().is_some(),
+            settings: save.get_settings().clone(),
+            rebinding: None,
+            displaced: None,
+        }
+    }
+
+    pub fn get_screen(&self) -> MenuScreen {
+        self.screen
+    }
+
+    pub fn get_selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn get_settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn show(&mut self, screen: MenuScreen) {
+        self.screen = screen;
+        self.selected = self
+            .get_entries()
+            .iter()
+            .position(|(entry, _)| *entry != MenuEntry::Text)
+            .unwrap_or(0);
+    }
+
+    pub fn get_title(&self) -> &str {
+        match self.screen {
+            MenuScreen::Title => "",
+            MenuScreen::Main => "MAIN MENU",
+            MenuScreen::LevelSelect => "SELECT CAVE",
+            MenuScreen::Options => "OPTIONS",
+            MenuScreen::Controls => "CONTROLS",
+            MenuScreen::HighScores => "HIGH SCORES",
+            MenuScreen::Credits => "CREDITS",
+        }
+    }
+
+    pub fn get_labels(&self) -> Vec<String> {
+        self.get_entries()
+            .into_iter()
+            .map(|(_, label)| label)
+            .collect()
+    }
+
+    fn get_entries(&self) -> Vec<(MenuEntry, String)> {
+        let mut entries = Vec::new();
+        match self.screen {
+            MenuScreen::Title => {}
+            MenuScreen::Main => {
+                if self.has_saved_game {
+                    entries.push((MenuEntry::Continue, "CONTINUE".to_string()));
+                }
+                entries.push((MenuEntry::Play, "PLAY".to_string()));
+                entries.push((MenuEntry::LevelSelect, "SELECT CAVE".to_string()));
+                entries.push((MenuEntry::RandomCave, "RANDOM CAVE".to_string()));
+                entries.push((MenuEntry::Options, "OPTIONS".to_string()));
+                entries.push((MenuEntry::HighScores, "HIGH SCORES".to_string()));
+                entries.push((MenuEntry::Credits, "CREDITS".to_string()));
+            }
+            MenuScreen::LevelSelect => {
+                for (index, (name, _)) in self.levels.iter().enumerate() {
+                    entries.push((
+                        MenuEntry::Level(index as u32 + 1),
+                        format!("CAVE {:02} {}", index + 1, name),
+                    ));
+                }
+                entries.push((MenuEntry::Back, "BACK".to_string()));
+            }
+            MenuScreen::Options => {
+                let sound = if self.settings.is_sound_enabled() {
+                    "ON"
+                } else {
+                    "OFF"
+                };
+                entries.push((MenuEntry::Sound, format!("SOUND {}", sound)));
+                entries.push((
+                    MenuEntry::Difficulty,
+                    format!("DIFFICULTY {}", self.settings.get_difficulty()),
+                ));
+                entries.push((MenuEntry::Controls, "CONTROLS".to_string()));
+                entries.push((MenuEntry::Back, "BACK".to_string()));
+            }
+            MenuScreen::Controls => {
+                for action in InputAction::BINDABLE {
+                    let keys = if self.rebinding == Some(action) {
+                        "PRESS A KEY".to_string()
+                    } else {
+                        self.settings
+                            .get_bindings()
+                            .get_keys(action)
+                            .into_iter()
+                            .filter(|key| !key.starts_with("Gamepad"))
+                            .map(|key| match key {
+                                " " => "SPACE".to_string(),
+                                key => key.to_uppercase(),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    };
+                    entries.push((
+                        MenuEntry::Binding(action),
+                        format!("{:<8} {}", action.get_name(), keys),
+                    ));
+                }
+                if let Some(action) = self.displaced {
+                    entries.push((
+                        MenuEntry::Text,
+                        format!("KEY TAKEN FROM {}", action.get_name()),
+                    ));
+                }
+                entries.push((MenuEntry::ResetControls, "RESET CONTROLS".to_string()));
+                entries.push((MenuEntry::Back, "BACK".to_string()));
+            }
+            MenuScreen::HighScores => {
+                for (index, (name, record)) in self.levels.iter().enumerate() {
+                    let score = match record {
+                        Some(record) => format!(
+                            "{:06} {:03}S",
+                            record.get_best_score(),
+                            record.get_best_time().ceil() as i32
+                        ),
+                        None => "------ ----".to_string(),
+                    };
+                    entries.push((
+                        MenuEntry::Text,
+                        format!("{:02} {:<16} {}", index + 1, name, score),
+                    ));
+                }
+                entries.push((MenuEntry::Back, "BACK".to_string()));
+            }
+            MenuScreen::Credits => {
+                for line in [
+                    "BOULDER DASH BY PETER LIEPA",
+                    "AND CHRIS GRAY",
+                    "",
+                    "REMAKE BY MDEVOLDE",
+                ] {
+                    entries.push((MenuEntry::Text, line.to_string()));
+                }
+                entries.push((MenuEntry::Back, "BACK".to_string()));
+            }
+        }
+        entries
+    }
+
+    pub fn key_down(&mut self, key: &str) -> Option<MenuChoice> {
+        if let Some(action) = self.rebinding.take() {
+            if key != "Escape" {
+                self.displaced = self.settings.get_bindings_mut().rebind(key, action);
+            }
+            return None;
+        }
+        self.displaced = None;
+        if self.screen == MenuScreen::Title {
+            self.show(MenuScreen::Main);
+            return None;
+        }
+        let entries = self.get_entries();
+        match key {
+            "Escape" | "Backspace" => self.back(),
+            "Enter" | " " => return self.activate(entries.get(self.selected)?.0),
+            _ => match self.settings.get_bindings().get_action(key) {
+                Some(InputAction::Move(Movement::MoveUp)) => self.move_selection(&entries, -1),
+                Some(InputAction::Move(Movement::MoveDown)) => self.move_selection(&entries, 1),
+                Some(InputAction::Move(Movement::MoveLeft)) => {
+                    self.adjust(entries.get(self.selected)?.0, -1)
+                }
+                Some(InputAction::Move(Movement::MoveRight)) => {
+                    self.adjust(entries.get(self.selected)?.0, 1)
+                }
+                _ => {}
+            },
+        }
+        None
+    }
+
+    fn back(&mut self) {
+        match self.screen {
+            MenuScreen::Main => self.show(MenuScreen::Title),
+            MenuScreen::Controls => self.show(MenuScreen::Options),
+            _ => self.show(MenuScreen::Main),
+        }
+    }
+
+    fn move_selection(&mut self, entries: &[(MenuEntry, String)], step: i32) {
+        let mut index = self.selected as i32;
+        loop {
+            index += step;
+            match entries.get(index as usize) {
+                Some((MenuEntry::Text, _)) => continue,
+                Some(_) if index >= 0 => {
+                    self.selected = index as usize;
+                    return;
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn adjust(&mut self, entry: MenuEntry, step: i32) {
+        match entry {
+            MenuEntry::Sound => {
+                let enabled = self.settings.is_sound_enabled();
+                self.settings.set_sound_enabled(!enabled);
+            }
+            MenuEntry::Difficulty => {
+                let difficulty = self.settings.get_difficulty() as i32 + step;
+                let difficulty = (difficulty - 1).rem_euclid(MAX_DIFFICULTY as i32) + 1;
+                self.settings.set_difficulty(difficulty as u32);
+            }
+            _ => {}
+        }
+    }
+
+    fn activate(&mut self, entry: MenuEntry) -> Option<MenuChoice> {
+        match entry {
+            MenuEntry::Continue => return Some(MenuChoice::Resume),
+            MenuEntry::Play => return Some(MenuChoice::Play { level: 1 }),
+            MenuEntry::Level(level) => return Some(MenuChoice::Play { level }),
+            MenuEntry::RandomCave => return Some(MenuChoice::RandomCave),
+            MenuEntry::LevelSelect => self.show(MenuScreen::LevelSelect),
+            MenuEntry::Options => self.show(MenuScreen::Options),
+            MenuEntry::HighScores => self.show(MenuScreen::HighScores),
+            MenuEntry::Credits => self.show(MenuScreen::Credits),
+            MenuEntry::Sound | MenuEntry::Difficulty => self.adjust(entry, 1),
+            MenuEntry::Controls => self.show(MenuScreen::Controls),
+            MenuEntry::Binding(action) => self.rebinding = Some(action),
+            MenuEntry::ResetControls => {
+                *self.settings.get_bindings_mut() = InputBindings::default()
+            }
+            MenuEntry::Back => self.back(),
+            MenuEntry::Text => {}
+        }
+        None
+    }
+}
@@ -0,0 +1,7 @@
+pub mod audio;
+pub mod camera;
+pub mod gamepad;
+pub mod local_storage;
+pub mod menu;
+pub mod overlay;
+pub mod renderer;
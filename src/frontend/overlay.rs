@@ -1,9 +1,8 @@
 use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
 
-use crate::game::{diamond::Diamond, grid::Grid, interfaces::renderable::Renderable};
-
-use super::zone::Zone;
+use crate::game::{grid::Grid, session::Session};
 
+#[derive(Default)]
 pub struct Overlay {}
 
 impl Overlay {
@@ -43,15 +42,16 @@ impl Overlay {
     pub fn render_diamonds_number(
         &self,
         grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
+        context: &CanvasRenderingContext2d,
         sprites: &HtmlImageElement,
     ) {
         let mut positions = vec![];
         positions
             .extend(self.get_sentence_positions(&grid.get_diamonds_number().to_string(), true));
         positions.extend(self.get_sentence_positions("^", false));
-        positions
-            .extend(self.get_sentence_positions(&grid.get_diamonds_number().to_string(), false));
+        positions.extend(
+            self.get_sentence_positions(&grid.get_current_diamond_value().to_string(), false),
+        );
         for (i, (x, y)) in positions.iter().enumerate() {
             context
                 .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
@@ -72,12 +72,10 @@ impl Overlay {
     pub fn render_diamonds_claimed(
         &self,
         grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
+        context: &CanvasRenderingContext2d,
         sprites: &HtmlImageElement,
     ) {
-        let mut diamonds_claimed = (grid.get_diamonds_number()
-            - grid.get_tiles_with_entity::<Diamond>().len() as i32)
-            .to_string();
+        let mut diamonds_claimed = grid.get_diamonds_claimed().to_string();
         if diamonds_claimed.len() == 1 {
             diamonds_claimed = format!("0{}", diamonds_claimed);
         }
@@ -103,7 +101,7 @@ impl Overlay {
     pub fn render_timer(
         &self,
         grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
+        context: &CanvasRenderingContext2d,
         sprites: &HtmlImageElement,
     ) {
         let mut timer = (grid.get_timer() as u64).to_string();
@@ -134,13 +132,11 @@ impl Overlay {
     pub fn render_score(
         &self,
         grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
+        session: &Session,
+        context: &CanvasRenderingContext2d,
         sprites: &HtmlImageElement,
     ) {
-        let mut score = ((grid.get_diamonds_number()
-            - grid.get_tiles_with_entity::<Diamond>().len() as i32)
-            * 10)
-            .to_string();
+        let mut score = session.get_total_score(grid).to_string();
         if score.len() == 1 {
             score = format!("00000{}", score);
         } else if score.len() == 2 {
@@ -170,19 +166,122 @@ impl Overlay {
                 .unwrap();
         }
     }
-}
 
-impl Renderable for Overlay {
-    fn render(
+    pub fn render_lives(
+        &self,
+        session: &Session,
+        context: &CanvasRenderingContext2d,
+        sprites: &HtmlImageElement,
+    ) {
+        let lives = format!("{:02}", session.get_lives());
+        self.render_sentence(context, sprites, &lives, true, (21.0, 0.0));
+    }
+
+    pub fn render_bombs(
         &self,
         grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
+        context: &CanvasRenderingContext2d,
+        sprites: &HtmlImageElement,
+    ) {
+        context.set_fill_style_str("black");
+        context.fill_rect(13.0 * 32.0, 0.0, 2.0 * 32.0, 32.0);
+        if grid.get_bombs_held() > 0 {
+            context
+                .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                    sprites,
+                    8.0 * 32.0,
+                    6.0 * 32.0,
+                    32.0,
+                    32.0,
+                    13.0 * 32.0,
+                    0.0,
+                    32.0,
+                    32.0,
+                )
+                .unwrap();
+            let bombs = grid.get_bombs_held().min(9).to_string();
+            self.render_sentence(context, sprites, &bombs, false, (14.0, 0.0));
+        }
+    }
+
+    pub fn render_sentence(
+        &self,
+        context: &CanvasRenderingContext2d,
         sprites: &HtmlImageElement,
-        _: &Zone,
+        sentence: &str,
+        yellow: bool,
+        (column, row): (f64, f64),
     ) {
+        for (i, c) in sentence.chars().enumerate() {
+            for (x, y) in self.get_sentence_positions(&c.to_string(), yellow) {
+                context
+                    .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                        sprites,
+                        x * 32.0,
+                        (y * 16.0) + 1.0,
+                        32.0,
+                        16.0,
+                        32.0 * (column + i as f64),
+                        32.0 * row,
+                        32.0,
+                        32.0,
+                    )
+                    .unwrap();
+            }
+        }
+    }
+
+    pub fn render_out_of_time(
+        &self,
+        context: &CanvasRenderingContext2d,
+        sprites: &HtmlImageElement,
+    ) {
+        self.clear_hud(context);
+        self.render_sentence(context, sprites, "OUT OF TIME", true, (10.0, 0.0));
+    }
+
+    pub fn clear_hud(&self, context: &CanvasRenderingContext2d) {
+        let canvas = context.canvas().expect("No canvas found");
+        context.set_fill_style_str("black");
+        context.fill_rect(0.0, 0.0, canvas.width() as f64, 32.0);
+    }
+
+    pub fn render_paused(&self, context: &CanvasRenderingContext2d, sprites: &HtmlImageElement) {
+        self.clear_hud(context);
+        self.render_sentence(context, sprites, "PAUSED", true, (12.0, 0.0));
+    }
+
+    pub fn render_game_over(
+        &self,
+        session: &Session,
+        context: &CanvasRenderingContext2d,
+        sprites: &HtmlImageElement,
+    ) {
+        let canvas = context.canvas().expect("No canvas found");
+        context.set_fill_style_str("black");
+        context.fill_rect(0.0, 0.0, canvas.width() as f64, canvas.height() as f64);
+        self.render_sentence(context, sprites, "GAME OVER", true, (10.5, 7.0));
+        let score = format!("SCORE {:06}", session.get_score());
+        self.render_sentence(context, sprites, &score, false, (9.5, 9.0));
+    }
+
+    pub fn render(
+        &self,
+        grid: &Grid,
+        session: &Session,
+        context: &CanvasRenderingContext2d,
+        sprites: &HtmlImageElement,
+    ) {
+        if grid.is_out_of_time() {
+            self.render_out_of_time(context, sprites);
+            return;
+        }
+        self.clear_hud(context);
         self.render_diamonds_number(grid, context, sprites);
         self.render_diamonds_claimed(grid, context, sprites);
         self.render_timer(grid, context, sprites);
-        self.render_score(grid, context, sprites);
+        self.render_bombs(grid, context, sprites);
+        self.render_lives(session, context, sprites);
+        self.render_score(grid, session, context, sprites);
     }
 }
@@ -0,0 +1,166 @@
+use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
+
+use std::any::Any;
+
+use crate::game::{
+    diamond::Diamond,
+    display::{animation::Animation, zone::Zone},
+    enemy::Enemy,
+    enums::magic_wall_state::MagicWallState,
+    grid::Grid,
+    interfaces::{collidable::Collidable, entity::Entity, renderable::Renderable},
+    tile::Tile,
+};
+
+pub struct Renderer {
+    context: CanvasRenderingContext2d,
+    sprites: HtmlImageElement,
+}
+
+impl Renderer {
+    pub fn new(context: CanvasRenderingContext2d, sprites: HtmlImageElement) -> Self {
+        Renderer { context, sprites }
+    }
+
+    pub fn get_context(&self) -> &CanvasRenderingContext2d {
+        &self.context
+    }
+
+    pub fn get_sprites(&self) -> &HtmlImageElement {
+        &self.sprites
+    }
+
+    pub fn get_canvas_size(&self) -> (i32, i32) {
+        let canvas = self.context.canvas().expect("No canvas found");
+        (canvas.width() as i32, canvas.height() as i32 - 32)
+    }
+
+    pub fn render_flash(&self) {
+        let (canvas_width, canvas_height) = self.get_canvas_size();
+        self.context.set_fill_style_str("white");
+        self.context
+            .fill_rect(0.0, 32.0, canvas_width as f64, canvas_height as f64);
+    }
+
+    pub fn draw_sprite(&self, (sx, sy): (f64, f64), (dx, dy): (f64, f64)) {
+        let _ = self
+            .context
+            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                &self.sprites,
+                sx,
+                sy,
+                32.0,
+                32.0,
+                dx,
+                dy + 32.0,
+                32.0,
+                32.0,
+            );
+    }
+
+    pub fn draw_scaled_sprite(&self, (sx, sy): (f64, f64), (dx, dy): (f64, f64), size: f64) {
+        let _ = self
+            .context
+            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                &self.sprites,
+                sx,
+                sy,
+                32.0,
+                32.0,
+                dx,
+                dy + 32.0,
+                size,
+                size,
+            );
+    }
+
+    pub fn render_level(&self, grid: &Grid, tile_size: f64) {
+        for y in 0..grid.get_height() {
+            for x in 0..grid.get_width() {
+                if let Some(tile) = grid.get_tile(x, y) {
+                    self.draw_scaled_sprite(
+                        tile.get_sprite_position(grid),
+                        (x as f64 * tile_size, y as f64 * tile_size),
+                        tile_size,
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn render_tile(&self, grid: &Grid, tile: &Tile, zone: &Zone) {
+        self.draw_sprite(
+            tile.get_sprite_position(grid),
+            zone.get_patched_position(tile.get_position()),
+        );
+    }
+
+    pub fn render_position(&self, grid: &Grid, (x, y): (i32, i32), zone: &Zone) {
+        if let Some(tile) = grid.get_tile(x, y) {
+            self.render_tile(grid, tile, zone);
+        }
+    }
+
+    pub fn render_zone(&self, grid: &Grid, zone: &Zone) {
+        for y in zone.get_sy()..zone.get_ey() {
+            for x in zone.get_sx()..zone.get_ex() {
+                let tile = grid.get_tile(x, y).expect("Tile not found");
+                self.render_tile(grid, tile, zone);
+            }
+        }
+    }
+
+    pub fn render_animated_entities(&self, grid: &Grid, zone: &Zone) {
+        self.render_entities::<Diamond>(grid, zone);
+        self.render_entities::<Enemy>(grid, zone);
+        for amoeba in grid.get_amoebas() {
+            let (x, y) = amoeba.get_position();
+            if zone.is_in_zone(x, y) {
+                self.render_position(grid, (x, y), zone);
+            }
+        }
+        if grid.is_exit_open() {
+            for (x, y) in grid.get_exit_positions() {
+                if zone.is_in_zone(x, y) {
+                    self.render_position(grid, (x, y), zone);
+                }
+            }
+        }
+        for slime in grid.get_slimes() {
+            let (x, y) = slime.get_position();
+            if zone.is_in_zone(x, y) {
+                self.render_position(grid, (x, y), zone);
+            }
+        }
+        if let MagicWallState::Active(_) = grid.get_magic_wall_state() {
+            for magic_wall in grid.get_magic_walls() {
+                let (x, y) = magic_wall.get_position();
+                if zone.is_in_zone(x, y) {
+                    self.render_position(grid, (x, y), zone);
+                }
+            }
+        }
+    }
+
+    fn render_entities<T: Entity + Any>(&self, grid: &Grid, zone: &Zone) {
+        for entity in grid.get_tiles_with_entity::<T>() {
+            let (x, y) = entity.get_position();
+            if zone.is_in_zone(x, y) {
+                self.render_position(grid, (x, y), zone);
+            }
+        }
+    }
+
+    pub fn render_animation(&self, animation: &Animation, zone: &Zone) {
+        let (x, y) = animation.get_position();
+        if !zone.is_in_zone(x, y) {
+            return;
+        }
+        if let Some(sprite_position) = animation.get_sprite_position() {
+            self.draw_sprite(
+                sprite_position,
+                zone.get_patched_position(animation.get_position()),
+            );
+        }
+    }
+}
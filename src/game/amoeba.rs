@@ -0,0 +1,126 @@
+use std::rc::Rc;
+
+use super::{
+    diamond::Diamond,
+    display::action::Action,
+    enums::{action_type::ActionType, field::Field, movement::Movement},
+    grid::Grid,
+    interfaces::{collidable::Collidable, renderable::Renderable},
+    random::Random,
+    rock::Rock,
+};
+
+pub const AMOEBA_FAST_GROWTH_RATE: u8 = 64;
+
+#[derive(Clone, Debug)]
+pub struct Amoeba {
+    position: (i32, i32),
+}
+
+impl Amoeba {
+    pub fn new(x: i32, y: i32) -> Self {
+        Amoeba { position: (x, y) }
+    }
+
+    pub fn get_amoeba_actions(grid: &Grid, random: &mut Random) -> Vec<Action> {
+        let amoebas = grid.get_amoebas();
+        if amoebas.is_empty() {
+            return vec![];
+        }
+
+        if amoebas.len() as i32 > grid.get_amoeba_max_size() {
+            return amoebas
+                .iter()
+                .map(|amoeba| {
+                    let (x, y) = amoeba.position;
+                    Action::new(
+                        (x, y),
+                        Field::Entity(Rc::new(Rock::new(x, y))),
+                        ActionType::AmoebaToRock,
+                    )
+                })
+                .collect();
+        }
+
+        if !amoebas.iter().any(|amoeba| amoeba.can_grow(grid)) {
+            return amoebas
+                .iter()
+                .map(|amoeba| {
+                    let (x, y) = amoeba.position;
+                    Action::new(
+                        (x, y),
+                        Field::Entity(Rc::new(Diamond::new(x, y))),
+                        ActionType::AmoebaToDiamond,
+                    )
+                })
+                .collect();
+        }
+
+        let mut actions = Vec::new();
+        for amoeba in amoebas {
+            if random.next_u8() >= grid.get_amoeba_growth_rate() {
+                continue;
+            }
+            let direction = Amoeba::get_directions()[random.next_u8() as usize % 4];
+            if Amoeba::can_grow_to(grid, amoeba.position, direction) {
+                let (x, y) = direction.edit_position(amoeba.position);
+                actions.push(Action::new(
+                    (x, y),
+                    Field::Amoeba(Amoeba::new(x, y)),
+                    ActionType::AmoebaGrow,
+                ));
+            }
+        }
+        actions
+    }
+
+    pub fn can_grow(&self, grid: &Grid) -> bool {
+        Amoeba::get_directions()
+            .iter()
+            .any(|direction| Amoeba::can_grow_to(grid, self.position, *direction))
+    }
+
+    fn can_grow_to(grid: &Grid, (x, y): (i32, i32), direction: Movement) -> bool {
+        grid.get_nearest_tile(x, y, direction).is_some_and(|tile| {
+            matches!(
+                tile.get_object_on(),
+                None | Some(Field::Dirt) | Some(Field::Empty)
+            )
+        })
+    }
+
+    fn get_directions() -> [Movement; 4] {
+        [
+            Movement::MoveUp,
+            Movement::MoveDown,
+            Movement::MoveLeft,
+            Movement::MoveRight,
+        ]
+    }
+
+    pub fn get_frame(&self, current_frame: i32) -> (f64, f64) {
+        let frame_x = if (0..=7).contains(&current_frame) {
+            current_frame as f64
+        } else {
+            0.0
+        };
+
+        (frame_x * 32.0, 8.0 * 32.0)
+    }
+}
+
+impl Collidable for Amoeba {
+    fn get_position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    fn get_future_position(&self, _: &Grid) -> (i32, i32) {
+        self.position
+    }
+}
+
+impl Renderable for Amoeba {
+    fn get_sprite_position(&self, grid: &Grid) -> (f64, f64) {
+        self.get_frame(grid.get_frame())
+    }
+}
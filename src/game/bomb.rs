@@ -0,0 +1,126 @@
+use std::{any::Any, rc::Rc};
+
+use super::{
+    display::action::Action,
+    enums::{action_type::ActionType, entity_state::EntityState, field::Field},
+    grid::Grid,
+    interfaces::{
+        collidable::Collidable, entity::Entity, movable::Movable, renderable::Renderable,
+    },
+};
+
+pub const BOMB_FUSE: i32 = 15;
+
+#[derive(Clone, Debug)]
+pub struct Bomb {
+    position: (i32, i32),
+    fuse: Option<i32>,
+}
+
+impl Bomb {
+    pub fn new(x: i32, y: i32) -> Self {
+        Bomb {
+            position: (x, y),
+            fuse: None,
+        }
+    }
+
+    pub fn lit(x: i32, y: i32, fuse: i32) -> Self {
+        Bomb {
+            position: (x, y),
+            fuse: Some(fuse),
+        }
+    }
+
+    pub fn get_bomb_actions(grid: &Grid) -> Vec<Action> {
+        let mut actions = vec![];
+        for bomb in grid.get_tiles_with_entity::<Bomb>() {
+            actions.extend(bomb.update(grid));
+        }
+        actions
+    }
+
+    pub fn is_lit(&self) -> bool {
+        self.fuse.is_some()
+    }
+}
+
+impl Movable for Bomb {
+    fn move_to(&self, _: &Grid, ax: i32, ay: i32, nx: i32, ny: i32) -> Vec<Action> {
+        let mut self_clone = self.clone();
+        self_clone.position = (nx, ny);
+        vec![
+            Action::new((ax, ay), Field::Empty, ActionType::NoMoreEntityOnTile),
+            Action::new(
+                (nx, ny),
+                Field::Entity(Rc::new(self_clone)),
+                ActionType::BombTick,
+            ),
+        ]
+    }
+}
+
+impl Collidable for Bomb {
+    fn get_position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    fn get_future_position(&self, _: &Grid) -> (i32, i32) {
+        self.position
+    }
+}
+
+impl Renderable for Bomb {
+    fn get_sprite_position(&self, _: &Grid) -> (f64, f64) {
+        match self.fuse {
+            Some(fuse) if fuse % 2 == 0 => (9.0 * 32.0, 6.0 * 32.0),
+            Some(_) => (8.0 * 32.0, 7.0 * 32.0),
+            None => (8.0 * 32.0, 6.0 * 32.0),
+        }
+    }
+}
+
+impl Entity for Bomb {
+    fn get_type(&self) -> String {
+        String::from("Bomb")
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn update(&self, grid: &Grid) -> Vec<Action> {
+        match self.fuse {
+            Some(fuse) if fuse <= 1 => {
+                grid.get_explosion_actions(self.position, ActionType::ExplodeToSpace)
+            }
+            Some(fuse) => {
+                let mut self_clone = self.clone();
+                self_clone.fuse = Some(fuse - 1);
+                vec![Action::new(
+                    self.position,
+                    Field::Entity(Rc::new(self_clone)),
+                    ActionType::BombTick,
+                )]
+            }
+            None => vec![],
+        }
+    }
+
+    fn get_state(&self) -> Option<EntityState> {
+        self.fuse.map(|fuse| EntityState::Burning { fuse })
+    }
+
+    fn restore_state(&self, state: EntityState) -> Option<Rc<dyn Entity>> {
+        let EntityState::Burning { fuse } = state else {
+            return None;
+        };
+        let mut self_clone = self.clone();
+        self_clone.fuse = Some(fuse);
+        Some(Rc::new(self_clone))
+    }
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Bomb at {:?}", self.position)
+    }
+}
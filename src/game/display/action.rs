@@ -1,10 +1,6 @@
 use crate::game::enums::action_type::ActionType;
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
 
-use super::{
-    super::{enums::field::Field, grid::Grid, interfaces::renderable::Renderable},
-    zone::Zone,
-};
+use super::super::{enums::field::Field, grid::Grid};
 
 #[derive(Debug)]
 pub struct Action {
@@ -36,17 +32,3 @@ impl Action {
         &self.action_type
     }
 }
-
-impl Renderable for Action {
-    fn render(
-        &self,
-        grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
-        if let Some(tile) = grid.get_tile(self.coordinates.0, self.coordinates.1) {
-            tile.render(grid, context, sprites, zone);
-        }
-    }
-}
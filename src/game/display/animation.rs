@@ -1,14 +1,10 @@
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
+use serde::{Deserialize, Serialize};
 
-use crate::game::{
-    enums::{action_type::ActionType, animation_type::AnimationType},
-    grid::Grid,
-    interfaces::renderable::Renderable,
-};
+use crate::game::enums::{action_type::ActionType, animation_type::AnimationType};
 
-use super::{action::Action, zone::Zone};
+use super::action::Action;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Animation {
     animation_type: AnimationType,
     duration: i32,
@@ -29,8 +25,18 @@ impl Animation {
     pub fn from_action(action: &Action) -> Option<Self> {
         match action.get_action_type() {
             ActionType::KillPlayer => {
-                Some(Self::new(AnimationType::Death, 6, action.get_position()))
+                Some(Self::new(AnimationType::Death, 8, action.get_position()))
             }
+            ActionType::ExplodeToSpace => Some(Self::new(
+                AnimationType::ExplodeToSpace,
+                5,
+                action.get_position(),
+            )),
+            ActionType::ExplodeToDiamond => Some(Self::new(
+                AnimationType::ExplodeToDiamond,
+                5,
+                action.get_position(),
+            )),
             _ => None,
         }
     }
@@ -44,48 +50,55 @@ impl Animation {
         }
     }
 
-    fn render_death(&self, _: &mut CanvasRenderingContext2d, _: &HtmlImageElement, _: &Zone) {}
+    pub fn is_blocking_input(&self) -> bool {
+        matches!(self.animation_type, AnimationType::Spawn)
+    }
 
-    fn render_spawn(
-        &self,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
-        let sy = 6.0;
-        let sx: f64;
-        let (dx, dy) = zone.get_patched_position(self.position);
-        if self.frame % 2 == 0 {
-            sx = 1.0;
-        } else {
-            sx = 2.0;
-        };
-        let _ = context
-            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
-                sprites,
-                sx * 32.0,
-                sy * 32.0,
-                32.0,
-                32.0,
-                dx,
-                dy + 32.0,
-                32.0,
-                32.0,
-            );
+    pub fn get_animation_type(&self) -> AnimationType {
+        self.animation_type
     }
-}
 
-impl Renderable for Animation {
-    fn render(
-        &self,
-        _: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
+    pub fn get_position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    pub fn get_sprite_position(&self) -> Option<(f64, f64)> {
         match self.animation_type {
-            AnimationType::Death => self.render_death(context, sprites, zone),
-            AnimationType::Spawn => self.render_spawn(context, sprites, zone),
-        };
+            AnimationType::Death => {
+                let frames = [
+                    (0.0, 0.0),
+                    (1.0, 0.0),
+                    (2.0, 0.0),
+                    (3.0, 0.0),
+                    (3.0, 0.0),
+                    (2.0, 0.0),
+                    (1.0, 0.0),
+                    (0.0, 6.0),
+                ];
+                Some(self.get_sequence_frame(&frames))
+            }
+            AnimationType::Spawn => {
+                let sx = if self.frame.is_multiple_of(2) {
+                    1.0
+                } else {
+                    2.0
+                };
+                Some((sx * 32.0, 6.0 * 32.0))
+            }
+            AnimationType::ExplodeToSpace => {
+                let frames = [(1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (2.0, 0.0), (1.0, 0.0)];
+                Some(self.get_sequence_frame(&frames))
+            }
+            AnimationType::ExplodeToDiamond => {
+                let frames = [(1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (4.0, 7.0), (6.0, 7.0)];
+                Some(self.get_sequence_frame(&frames))
+            }
+        }
+    }
+
+    fn get_sequence_frame(&self, frames: &[(f64, f64)]) -> (f64, f64) {
+        let index = (self.frame as usize).min(frames.len() - 1);
+        let (sx, sy) = frames[index];
+        (sx * 32.0, sy * 32.0)
     }
 }
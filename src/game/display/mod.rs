@@ -1,5 +1,4 @@
 pub mod action;
 pub mod animation;
-pub mod overlay;
 pub mod scroller;
 pub mod zone;
@@ -1,7 +1,3 @@
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
-
-use crate::game::{grid::Grid, interfaces::renderable::Renderable};
-
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Zone {
     start_x: i32,
@@ -129,20 +125,3 @@ impl Zone {
         x >= self.start_x && x < self.end_x && y >= self.start_y && y < self.end_y
     }
 }
-
-impl Renderable for Zone {
-    fn render(
-        &self,
-        grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        _: &Zone,
-    ) {
-        for y in self.get_sy()..self.get_ey() {
-            for x in self.get_sx()..self.get_ex() {
-                let tile = grid.get_tile(x, y).expect("Tile not found");
-                tile.render(grid, context, sprites, self);
-            }
-        }
-    }
-}
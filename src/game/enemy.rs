@@ -0,0 +1,177 @@
+use std::{any::Any, rc::Rc};
+
+use super::{
+    display::action::Action,
+    enums::{
+        action_type::ActionType, enemy_kind::EnemyKind, entity_state::EntityState, field::Field,
+        movement::Movement,
+    },
+    grid::Grid,
+    interfaces::{
+        collidable::Collidable, entity::Entity, explodable::Explodable, movable::Movable,
+        renderable::Renderable,
+    },
+};
+
+#[derive(Clone)]
+pub struct Enemy {
+    position: (i32, i32),
+    direction: Movement,
+    kind: EnemyKind,
+}
+
+impl Enemy {
+    pub fn new(x: i32, y: i32, kind: EnemyKind) -> Self {
+        Enemy {
+            position: (x, y),
+            direction: kind.get_start_direction(),
+            kind,
+        }
+    }
+
+    pub fn get_enemy_positions(grid: &Grid, kind: EnemyKind) -> Vec<(i32, i32)> {
+        grid.get_tiles_with_entity::<Enemy>()
+            .into_iter()
+            .filter(|enemy| enemy.kind == kind)
+            .map(|enemy| enemy.position)
+            .collect()
+    }
+
+    pub fn get_kind(&self) -> EnemyKind {
+        self.kind
+    }
+
+    pub fn get_frame(&self, current_frame: i32) -> (f64, f64) {
+        let frame_x = if (0..=7).contains(&current_frame) {
+            current_frame as f64
+        } else {
+            0.0
+        };
+
+        (frame_x * 32.0, self.kind.get_sprite_row() * 32.0)
+    }
+
+    fn can_move_to(&self, grid: &Grid, direction: Movement) -> bool {
+        grid.get_nearest_tile(self.position.0, self.position.1, direction)
+            .is_some_and(|tile| tile.get_object_on().is_none())
+    }
+
+    fn turn(&self, direction: Movement) -> Action {
+        let mut self_clone = self.clone();
+        self_clone.direction = direction;
+        Action::new(
+            self.position,
+            Field::Entity(Rc::new(self_clone)),
+            ActionType::EnemyTurn,
+        )
+    }
+}
+
+impl Movable for Enemy {
+    fn move_to(&self, _: &Grid, ax: i32, ay: i32, nx: i32, ny: i32) -> Vec<Action> {
+        let mut self_clone = self.clone();
+        self_clone.position = (nx, ny);
+        vec![
+            Action::new((ax, ay), Field::Empty, ActionType::NoMoreEntityOnTile),
+            Action::new(
+                (nx, ny),
+                Field::Entity(Rc::new(self_clone)),
+                ActionType::EnemyMove,
+            ),
+        ]
+    }
+}
+
+impl Collidable for Enemy {
+    fn get_position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    fn get_future_position(&self, grid: &Grid) -> (i32, i32) {
+        let towards_wall = self.kind.turn_towards_wall(self.direction);
+        if self.can_move_to(grid, towards_wall) {
+            towards_wall.edit_position(self.position)
+        } else if self.can_move_to(grid, self.direction) {
+            self.direction.edit_position(self.position)
+        } else {
+            self.position
+        }
+    }
+}
+
+impl Renderable for Enemy {
+    fn get_sprite_position(&self, grid: &Grid) -> (f64, f64) {
+        self.get_frame(grid.get_frame())
+    }
+}
+
+impl Entity for Enemy {
+    fn get_type(&self) -> String {
+        String::from(self.kind.get_name())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn update(&self, grid: &Grid) -> Vec<Action> {
+        if self.is_touching_player(grid) {
+            return self.explode(grid);
+        }
+
+        let (x, y) = self.position;
+        let towards_wall = self.kind.turn_towards_wall(self.direction);
+        if self.can_move_to(grid, towards_wall) {
+            let mut self_clone = self.clone();
+            self_clone.direction = towards_wall;
+            let (nx, ny) = towards_wall.edit_position(self.position);
+            self_clone.move_to(grid, x, y, nx, ny)
+        } else if self.can_move_to(grid, self.direction) {
+            let (nx, ny) = self.direction.edit_position(self.position);
+            self.move_to(grid, x, y, nx, ny)
+        } else {
+            vec![self.turn(self.kind.turn_away_from_wall(self.direction))]
+        }
+    }
+
+    fn get_state(&self) -> Option<EntityState> {
+        Some(EntityState::Heading {
+            direction: self.direction,
+        })
+    }
+
+    fn restore_state(&self, state: EntityState) -> Option<Rc<dyn Entity>> {
+        let EntityState::Heading { direction } = state else {
+            return None;
+        };
+        let mut self_clone = self.clone();
+        self_clone.direction = direction;
+        Some(Rc::new(self_clone))
+    }
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {:?}", self.kind.get_name(), self.position)
+    }
+}
+
+impl Explodable for Enemy {
+    fn explode(&self, grid: &Grid) -> Vec<Action> {
+        grid.get_explosion_actions(self.position, self.kind.get_explosion_type())
+    }
+
+    fn is_touching_player(&self, grid: &Grid) -> bool {
+        let directions = [
+            Movement::MoveUp,
+            Movement::MoveDown,
+            Movement::MoveLeft,
+            Movement::MoveRight,
+        ];
+        directions.iter().any(|direction| {
+            matches!(
+                grid.get_nearest_tile(self.position.0, self.position.1, *direction)
+                    .and_then(|tile| tile.get_object_on()),
+                Some(Field::Entity(entity)) if entity.get_type().as_str() == "Player"
+            )
+        })
+    }
+}
@@ -1,6 +1,6 @@
-use web_sys::AudioBuffer;
+use super::sound::Sound;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ActionType {
     WalkOnDirt,
     ClaimDiamond,
@@ -14,16 +14,32 @@ pub enum ActionType {
     PlayerSetPush,
     PlayerCancelPush,
     NoMoreEntityOnTile,
+    EnemyMove,
+    EnemyTurn,
+    ExplodeToSpace,
+    ExplodeToDiamond,
+    AmoebaGrow,
+    AmoebaToRock,
+    AmoebaToDiamond,
+    MagicWallActivate,
+    MagicWallTransform,
+    WallExpand,
+    SlimeLetThrough,
+    CollectBomb,
+    DropBomb,
+    BombTick,
 }
 
 impl ActionType {
-    pub fn get_linked_sound<'a>(&self, sounds: &'a Vec<AudioBuffer>) -> Option<&'a AudioBuffer> {
+    pub fn get_linked_sound(&self) -> Option<Sound> {
         match self {
-            ActionType::ClaimDiamond => Some(&sounds[0]),
-            ActionType::DiamondFallOnSomething => Some(&sounds[1]),
-            ActionType::PlayerMove => Some(&sounds[2]),
-            ActionType::RockFallOnSomethingOrPushed => Some(&sounds[3]),
-            ActionType::WalkOnDirt => Some(&sounds[4]),
+            ActionType::ClaimDiamond | ActionType::CollectBomb => Some(Sound::ClaimDiamond),
+            ActionType::DiamondFallOnSomething => Some(Sound::DiamondFallOnSomething),
+            ActionType::PlayerMove => Some(Sound::PlayerMove),
+            ActionType::RockFallOnSomethingOrPushed => Some(Sound::RockFallOnSomethingOrPushed),
+            ActionType::WalkOnDirt => Some(Sound::WalkOnDirt),
+            ActionType::MagicWallTransform => Some(Sound::MagicWall),
+            ActionType::KillPlayer => Some(Sound::Death),
             _ => None,
         }
     }
@@ -1,5 +1,9 @@
-#[derive(Clone, Copy, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum AnimationType {
     Spawn,
     Death,
+    ExplodeToSpace,
+    ExplodeToDiamond,
 }
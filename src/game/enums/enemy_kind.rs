@@ -0,0 +1,51 @@
+use super::{action_type::ActionType, movement::Movement};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EnemyKind {
+    Firefly,
+    Butterfly,
+}
+
+impl EnemyKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EnemyKind::Firefly => "Firefly",
+            EnemyKind::Butterfly => "Butterfly",
+        }
+    }
+
+    pub fn get_start_direction(&self) -> Movement {
+        match self {
+            EnemyKind::Firefly => Movement::MoveLeft,
+            EnemyKind::Butterfly => Movement::MoveDown,
+        }
+    }
+
+    pub fn turn_towards_wall(&self, direction: Movement) -> Movement {
+        match self {
+            EnemyKind::Firefly => direction.turn_left(),
+            EnemyKind::Butterfly => direction.turn_right(),
+        }
+    }
+
+    pub fn turn_away_from_wall(&self, direction: Movement) -> Movement {
+        match self {
+            EnemyKind::Firefly => direction.turn_right(),
+            EnemyKind::Butterfly => direction.turn_left(),
+        }
+    }
+
+    pub fn get_sprite_row(&self) -> f64 {
+        match self {
+            EnemyKind::Firefly => 9.0,
+            EnemyKind::Butterfly => 11.0,
+        }
+    }
+
+    pub fn get_explosion_type(&self) -> ActionType {
+        match self {
+            EnemyKind::Firefly => ActionType::ExplodeToSpace,
+            EnemyKind::Butterfly => ActionType::ExplodeToDiamond,
+        }
+    }
+}
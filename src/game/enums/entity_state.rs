@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use super::movement::Movement;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityState {
+    Falling {
+        falling_since: i32,
+    },
+    Heading {
+        direction: Movement,
+    },
+    Player {
+        doing: Movement,
+        pushing: Option<Movement>,
+        bomb_fuse: Option<i32>,
+    },
+    Burning {
+        fuse: i32,
+    },
+}
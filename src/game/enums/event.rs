@@ -0,0 +1,10 @@
+use super::sound::Sound;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Event {
+    TileChanged((i32, i32)),
+    PlaySound(Sound),
+    ExitOpened,
+    LevelCompleted,
+    GameOver,
+}
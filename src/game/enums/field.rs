@@ -1,6 +1,11 @@
 use std::rc::Rc;
 
-use crate::game::{interfaces::entity::Entity, wall::Wall};
+use super::{enemy_kind::EnemyKind, wall_material::WallMaterial};
+
+use crate::game::{
+    amoeba::Amoeba, bomb::Bomb, diamond::Diamond, enemy::Enemy, interfaces::entity::Entity,
+    magic_wall::MagicWall, player::Player, rock::Rock, slime::Slime, wall::Wall,
+};
 
 #[derive(Clone, Debug)]
 pub enum Field {
@@ -9,4 +14,55 @@ pub enum Field {
     Wall(Wall),
     Entity(Rc<dyn Entity>),
     Exit,
+    Amoeba(Amoeba),
+    MagicWall(MagicWall),
+    Slime(Slime),
+}
+
+impl Field {
+    pub fn from_char(ch: char, x: i32, y: i32) -> Option<Field> {
+        match ch {
+            ' ' => Some(Field::Empty),
+            '.' => Some(Field::Dirt),
+            'W' | 'w' | 'x' | 'v' => {
+                Some(Field::Wall(Wall::new(x, y, WallMaterial::from_char(ch)?)))
+            }
+            'r' => Some(Field::Entity(Rc::new(Rock::new(x, y)))),
+            'd' => Some(Field::Entity(Rc::new(Diamond::new(x, y)))),
+            'P' => Some(Field::Entity(Rc::new(Player::new(x, y)))),
+            'X' => Some(Field::Exit),
+            'q' => Some(Field::Entity(Rc::new(Enemy::new(x, y, EnemyKind::Firefly)))),
+            'B' => Some(Field::Entity(Rc::new(Enemy::new(
+                x,
+                y,
+                EnemyKind::Butterfly,
+            )))),
+            'b' => Some(Field::Entity(Rc::new(Bomb::new(x, y)))),
+            'a' => Some(Field::Amoeba(Amoeba::new(x, y))),
+            'M' => Some(Field::MagicWall(MagicWall::new(x, y))),
+            's' => Some(Field::Slime(Slime::new(x, y))),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(&self) -> char {
+        match self {
+            Field::Empty => ' ',
+            Field::Dirt => '.',
+            Field::Wall(wall) => wall.get_material().to_char(),
+            Field::Exit => 'X',
+            Field::Amoeba(_) => 'a',
+            Field::MagicWall(_) => 'M',
+            Field::Slime(_) => 's',
+            Field::Entity(entity) => match entity.get_type().as_str() {
+                "Rock" => 'r',
+                "Diamond" => 'd',
+                "Player" => 'P',
+                "Firefly" => 'q',
+                "Butterfly" => 'B',
+                "Bomb" => 'b',
+                _ => ' ',
+            },
+        }
+    }
 }
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use super::movement::Movement;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum InputAction {
+    Move(Movement),
+    Snap,
+    Bomb,
+    Pause,
+    Restart,
+    Quit,
+}
+
+impl InputAction {
+    pub const BINDABLE: [InputAction; 9] = [
+        InputAction::Move(Movement::MoveUp),
+        InputAction::Move(Movement::MoveDown),
+        InputAction::Move(Movement::MoveLeft),
+        InputAction::Move(Movement::MoveRight),
+        InputAction::Snap,
+        InputAction::Bomb,
+        InputAction::Pause,
+        InputAction::Restart,
+        InputAction::Quit,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            InputAction::Move(Movement::MoveUp) => "UP",
+            InputAction::Move(Movement::MoveDown) => "DOWN",
+            InputAction::Move(Movement::MoveLeft) => "LEFT",
+            InputAction::Move(Movement::MoveRight) => "RIGHT",
+            InputAction::Move(_) => "WAIT",
+            InputAction::Snap => "SNAP",
+            InputAction::Bomb => "BOMB",
+            InputAction::Pause => "PAUSE",
+            InputAction::Restart => "RESTART",
+            InputAction::Quit => "QUIT",
+        }
+    }
+}
@@ -1,4 +1,12 @@
 pub mod action_type;
 pub mod animation_type;
+pub mod enemy_kind;
+pub mod engine_mode;
+pub mod entity_state;
+pub mod event;
 pub mod field;
+pub mod input_action;
+pub mod magic_wall_state;
 pub mod movement;
+pub mod sound;
+pub mod wall_material;
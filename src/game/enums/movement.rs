@@ -1,20 +1,71 @@
-#[derive(Clone, Copy, PartialEq, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Movement {
     MoveUp,
     MoveDown,
     MoveLeft,
     MoveRight,
+    SnapUp,
+    SnapDown,
+    SnapLeft,
+    SnapRight,
+    DropBomb,
     Afk,
 }
 
 impl Movement {
     pub fn edit_position(&self, position: (i32, i32)) -> (i32, i32) {
-        match self {
+        match self.get_direction() {
             Movement::MoveUp => (position.0, position.1 - 1),
             Movement::MoveDown => (position.0, position.1 + 1),
             Movement::MoveLeft => (position.0 - 1, position.1),
             Movement::MoveRight => (position.0 + 1, position.1),
-            Movement::Afk => position,
+            _ => position,
+        }
+    }
+
+    pub fn snap(&self) -> Movement {
+        match self {
+            Movement::MoveUp => Movement::SnapUp,
+            Movement::MoveDown => Movement::SnapDown,
+            Movement::MoveLeft => Movement::SnapLeft,
+            Movement::MoveRight => Movement::SnapRight,
+            other => *other,
+        }
+    }
+
+    pub fn get_direction(&self) -> Movement {
+        match self {
+            Movement::SnapUp => Movement::MoveUp,
+            Movement::SnapDown => Movement::MoveDown,
+            Movement::SnapLeft => Movement::MoveLeft,
+            Movement::SnapRight => Movement::MoveRight,
+            other => *other,
+        }
+    }
+
+    pub fn is_snap(&self) -> bool {
+        self.get_direction() != *self
+    }
+
+    pub fn turn_left(&self) -> Movement {
+        match self.get_direction() {
+            Movement::MoveUp => Movement::MoveLeft,
+            Movement::MoveLeft => Movement::MoveDown,
+            Movement::MoveDown => Movement::MoveRight,
+            Movement::MoveRight => Movement::MoveUp,
+            _ => Movement::Afk,
+        }
+    }
+
+    pub fn turn_right(&self) -> Movement {
+        match self.get_direction() {
+            Movement::MoveUp => Movement::MoveRight,
+            Movement::MoveRight => Movement::MoveDown,
+            Movement::MoveDown => Movement::MoveLeft,
+            Movement::MoveLeft => Movement::MoveUp,
+            _ => Movement::Afk,
         }
     }
 }
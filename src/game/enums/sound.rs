@@ -0,0 +1,12 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sound {
+    ClaimDiamond,
+    DiamondFallOnSomething,
+    PlayerMove,
+    RockFallOnSomethingOrPushed,
+    WalkOnDirt,
+    MagicWall,
+    TimeBonus,
+    ExitOpen,
+    Death,
+}
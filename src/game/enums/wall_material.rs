@@ -0,0 +1,55 @@
+use super::movement::Movement;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WallMaterial {
+    Steel,
+    Brick,
+    ExpandingHorizontal,
+    ExpandingVertical,
+}
+
+impl WallMaterial {
+    pub fn from_char(ch: char) -> Option<WallMaterial> {
+        match ch {
+            'W' => Some(WallMaterial::Steel),
+            'w' => Some(WallMaterial::Brick),
+            'x' => Some(WallMaterial::ExpandingHorizontal),
+            'v' => Some(WallMaterial::ExpandingVertical),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(&self) -> char {
+        match self {
+            WallMaterial::Steel => 'W',
+            WallMaterial::Brick => 'w',
+            WallMaterial::ExpandingHorizontal => 'x',
+            WallMaterial::ExpandingVertical => 'v',
+        }
+    }
+
+    pub fn is_rounded(&self) -> bool {
+        !matches!(self, WallMaterial::Steel)
+    }
+
+    pub fn is_explodable(&self) -> bool {
+        !matches!(self, WallMaterial::Steel)
+    }
+
+    pub fn get_expansion_directions(&self) -> &'static [Movement] {
+        match self {
+            WallMaterial::ExpandingHorizontal => &[Movement::MoveLeft, Movement::MoveRight],
+            WallMaterial::ExpandingVertical => &[Movement::MoveUp, Movement::MoveDown],
+            WallMaterial::Steel | WallMaterial::Brick => &[],
+        }
+    }
+
+    pub fn get_sprite_position(&self) -> (f64, f64) {
+        match self {
+            WallMaterial::Steel => (32.0, (6 * 32) as f64),
+            WallMaterial::Brick => ((3 * 32) as f64, (6 * 32) as f64),
+            WallMaterial::ExpandingHorizontal => ((5 * 32) as f64, 0.0),
+            WallMaterial::ExpandingVertical => ((6 * 32) as f64, 0.0),
+        }
+    }
+}
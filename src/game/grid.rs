@@ -1,286 +1,451 @@
-use std::{any::Any, rc::Rc};
-use web_sys::{AudioBuffer, AudioContext, CanvasRenderingContext2d, HtmlImageElement};
+use std::{any::Any, rc::Rc, str::FromStr};
 
 use super::{
-    diamond::{self, Diamond},
-    display::{
-        action::Action, animation::Animation, overlay::Overlay, scroller::Scroller, zone::Zone,
-    },
+    amoeba::{Amoeba, AMOEBA_FAST_GROWTH_RATE},
+    bomb::Bomb,
+    diamond::Diamond,
+    display::{action::Action, animation::Animation},
+    enemy::Enemy,
     enums::{
-        action_type::ActionType, animation_type::AnimationType, field::Field, movement::Movement,
+        action_type::ActionType, animation_type::AnimationType, enemy_kind::EnemyKind,
+        engine_mode::EngineMode, event::Event, field::Field, magic_wall_state::MagicWallState,
+        movement::Movement, sound::Sound,
+    },
+    interfaces::{collidable::Collidable, entity::Entity},
+    level::{
+        definition::{LevelDefinition, LevelHeader},
+        error::LevelParseError,
     },
-    interfaces::{collidable::Collidable, entity::Entity, renderable::Renderable},
+    magic_wall::MagicWall,
     player::Player,
+    random::Random,
     rock::Rock,
+    slime::Slime,
+    snapshot::{SavedEntity, Snapshot},
     tile::Tile,
     wall::Wall,
 };
 
 #[derive(Debug)]
 pub struct Grid {
+    header: LevelHeader,
     tiles: Vec<Vec<Tile>>,
+    width: i32,
+    height: i32,
     player_position: (i32, i32),
     diamonds_number: i32,
+    diamonds_claimed: i32,
+    bombs_held: i32,
+    diamond_value: i32,
+    extra_diamond_value: i32,
     timer: f64,
-    zones: Vec<Zone>,
-    scroller: Option<Scroller>,
-    animation: Option<Animation>,
+    elapsed: f64,
+    out_of_time: bool,
+    score: i32,
+    animations: Vec<Animation>,
+    restart_delay: i32,
+    random: Random,
+    amoeba_max_size: i32,
+    amoeba_growth_rate: u8,
+    amoeba_time: Option<f64>,
+    magic_wall_time: f64,
+    magic_wall_state: MagicWallState,
+    slime_permeability: u8,
+    open_slimes: Vec<(i32, i32)>,
+    intermission: bool,
     frame: i32,
     last_frame_direction: Movement,
     last_frame_side_direction: Movement,
 }
 
 impl Grid {
-    pub fn new(level_text: &str, canvas_sx: i32, canvas_sy: i32) -> Self {
-        Grid::from_str(&level_text, canvas_sx, canvas_sy)
-    }
-
-    pub fn default() -> Self {
-        Grid {
-            tiles: vec![],
-            player_position: (0, 0),
-            diamonds_number: 0,
-            timer: 0.0,
-            zones: vec![],
-            scroller: None,
-            animation: None,
-            frame: 0,
-            last_frame_direction: Movement::Afk,
-            last_frame_side_direction: Movement::Afk,
-        }
-    }
-
-    pub fn from_str(input: &str, canvas_sx: i32, canvas_sy: i32) -> Self {
-        let mut lines = input.lines();
-
-        let size_line = lines.next().expect("No size line found");
-        let mut size_iter = size_line.split_whitespace();
-        let height: i32 = size_iter
-            .next()
-            .expect("Missing part in height")
-            .parse()
-            .expect("Could not parse height");
-        let width: i32 = size_iter
-            .next()
-            .expect("Missing part in width")
-            .parse()
-            .expect("Could not parse width");
-
-        let player_line = lines.next().expect("No player line found");
-        let mut player_iter = player_line.split_whitespace();
-        let player_x: i32 = player_iter
-            .next()
-            .expect("Missing part in x ")
-            .parse()
-            .expect("Could not parse player x");
-        let player_y: i32 = player_iter
-            .next()
-            .expect("Missing part in y")
-            .parse()
-            .expect("Could not parse player y");
-
-        let diamonds_line = lines.next().expect("No diamonds line found");
-        let diamonds_number: i32 = diamonds_line.parse().expect("Could not parse diamonds");
-
-        lines.next();
+    pub fn new(level: &LevelDefinition) -> Self {
+        let header = level.get_header();
+        let (player_x, player_y) = level.get_player_position().unwrap_or_default();
 
         let mut tiles = Vec::new();
-        for (y, line) in lines.enumerate() {
+        for (y, line) in level.get_map().iter().enumerate() {
             let mut row = Vec::new();
-            for (x, ch) in line.chars().enumerate() {
-                let tile = Tile::new(
-                    x as i32,
-                    y as i32,
-                    match ch {
-                        'W' => Field::Wall(Wall::new(x as i32, y as i32)),
-                        'r' => Field::Entity(Rc::new(Rock::new(x as i32, y as i32))),
-                        'd' => Field::Entity(Rc::new(diamond::Diamond::new(x as i32, y as i32))),
-                        '.' => Field::Dirt,
-                        'P' => Field::Entity(Rc::new(Player::new(x as i32, y as i32))),
-                        'X' => Field::Exit,
-                        _ => Field::Empty,
-                    },
-                );
-                row.push(tile);
+            for (x, ch) in line.iter().enumerate() {
+                let field = Field::from_char(*ch, x as i32, y as i32).unwrap_or(Field::Empty);
+                row.push(Tile::new(x as i32, y as i32, field));
             }
             tiles.push(row);
         }
 
-        let zones = Zone::from_map(width, height, canvas_sx, canvas_sy);
-
-        let animation = Some(Animation::new(
+        let animations = vec![Animation::new(
             AnimationType::Spawn,
             40,
             (player_x, player_y),
-        ));
+        )];
 
         Grid {
+            header: header.clone(),
             tiles,
+            width: level.get_width(),
+            height: level.get_height(),
             player_position: (player_x, player_y),
-            diamonds_number,
-            timer: 150.0,
-            zones,
-            scroller: None,
-            animation,
+            diamonds_number: header.get_diamonds_required(),
+            diamonds_claimed: 0,
+            bombs_held: 0,
+            diamond_value: header.get_diamond_value(),
+            extra_diamond_value: header.get_extra_diamond_value(),
+            timer: header.get_cave_time(),
+            elapsed: 0.0,
+            out_of_time: false,
+            score: 0,
+            animations,
+            restart_delay: 60,
+            random: Random::new(header.get_seed()),
+            amoeba_max_size: header.get_amoeba_max_size(),
+            amoeba_growth_rate: header.get_amoeba_growth_rate(),
+            amoeba_time: Some(header.get_amoeba_time()).filter(|time| *time > 0.0),
+            magic_wall_time: header.get_magic_wall_time(),
+            intermission: header.is_intermission(),
+            magic_wall_state: MagicWallState::Dormant,
+            slime_permeability: header.get_slime_permeability(),
+            open_slimes: Vec::new(),
             frame: 0,
             last_frame_direction: Movement::Afk,
             last_frame_side_direction: Movement::Afk,
         }
     }
 
-    pub fn update(
-        &mut self,
-        context: &mut CanvasRenderingContext2d,
-        audio_context: &mut AudioContext,
-        sprites: &HtmlImageElement,
-        sounds: &Vec<AudioBuffer>,
-    ) {
-        if self.frame % 2 == 0 {
-            let actions = Rock::get_rock_actions(self);
-            self.apply_actions(actions, context, audio_context, sprites, sounds);
-        }
+    pub fn to_level_definition(&self) -> LevelDefinition {
+        let mut header = self.header.clone();
+        header.set_cave_time(self.timer);
+        header.set_diamonds_required(self.diamonds_number);
+        let map = self
+            .tiles
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|tile| tile.get_object_on().map_or(' ', |field| field.to_char()))
+                    .collect()
+            })
+            .collect();
+        LevelDefinition::new(header, map)
+    }
 
-        let zones = self.zones.clone();
-        let zone = Zone::get_current_zone(self.player_position.0, self.player_position.1, &zones)
-            .expect("No zone found for player");
+    pub fn to_level_string(&self) -> String {
+        self.to_level_definition().to_level_string()
+    }
 
-        self.scroll_if_needed(context, sprites, zone, &zones);
+    pub fn to_snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new(
+            self.to_level_definition(),
+            self.score,
+            self.diamonds_claimed,
+            self.frame,
+            self.magic_wall_state,
+            self.random,
+            self.amoeba_time,
+        );
+        snapshot.set_bombs_held(self.bombs_held);
+        snapshot.set_elapsed(self.elapsed);
+        snapshot.set_out_of_time(self.out_of_time);
+        let mut entities = vec![];
+        for row in &self.tiles {
+            for tile in row {
+                if let Some(Field::Entity(entity)) = tile.get_object_on() {
+                    if let Some(state) = entity.get_state() {
+                        entities.push(SavedEntity::new(tile.get_position(), state));
+                    }
+                }
+            }
+        }
+        snapshot.set_entities(entities);
+        snapshot.set_animations(self.animations.clone());
+        snapshot
+    }
 
-        if self.frame % 2 == 0 {
-            let actions = Player::get_player_actions(self);
-            self.set_last_frame_direction_afk_if_needed(&actions);
-            self.apply_actions(actions, context, audio_context, sprites, sounds);
+    pub fn from_snapshot(snapshot: &Snapshot) -> Result<Self, LevelParseError> {
+        let mut grid = Grid::new(&snapshot.get_level()?);
+        grid.score = snapshot.get_score();
+        grid.diamonds_claimed = snapshot.get_diamonds_claimed();
+        grid.bombs_held = snapshot.get_bombs_held();
+        grid.frame = snapshot.get_frame();
+        grid.magic_wall_state = snapshot.get_magic_wall_state();
+        grid.random = snapshot.get_random();
+        grid.amoeba_time = snapshot.get_amoeba_time();
+        grid.elapsed = snapshot.get_elapsed();
+        grid.out_of_time = snapshot.is_out_of_time();
+        for saved in snapshot.get_entities() {
+            let (x, y) = saved.get_position();
+            let Some(tile) = grid.get_mut_tile(x, y) else {
+                continue;
+            };
+            let restored = match tile.get_object_on() {
+                Some(Field::Entity(entity)) => entity.restore_state(saved.get_state()),
+                _ => None,
+            };
+            if let Some(entity) = restored {
+                tile.set_object_on(Field::Entity(entity));
+            }
         }
+        grid.animations = snapshot.get_animations().to_vec();
+        Ok(grid)
+    }
 
-        if let Some(player) = self.get_tiles_with_entity::<Player>().get(0) {
-            self.player_position = player.get_position();
+    pub fn step(&mut self, input: Movement) -> Vec<Event> {
+        if self.is_level_completed() {
+            return self.tally_time_bonus();
         }
 
-        self.set_scroller_if_needed(zone);
+        let mut events = Vec::new();
+        if input != Movement::Afk {
+            self.set_player_doing(input);
+        }
 
-        if self.frame % 2 == 0 {
-            let actions = Diamond::get_diamond_actions(self);
-            self.apply_actions(actions, context, audio_context, sprites, sounds);
-        } else {
-            self.render_diamonds_gif(context, sprites, zone);
+        let scan_order = self.header.get_engine() == EngineMode::ScanOrder;
+        if self.is_scan_frame() {
+            self.update_slimes();
+            if scan_order {
+                events.extend(self.scan());
+            } else {
+                let actions = Rock::get_rock_actions(self);
+                events.extend(self.apply_actions(actions));
+
+                let actions = Player::get_player_actions(self);
+                self.set_last_frame_direction_afk_if_needed(&actions);
+                events.extend(self.apply_actions(actions));
+
+                events.extend(self.update_enemies(EnemyKind::Firefly));
+                events.extend(self.update_enemies(EnemyKind::Butterfly));
+
+                let actions = Bomb::get_bomb_actions(self);
+                events.extend(self.apply_actions(actions));
+            }
+
+            let mut random = self.random;
+            let actions = Amoeba::get_amoeba_actions(self, &mut random);
+            self.random = random;
+            events.extend(self.apply_actions(actions));
+
+            let actions = Wall::get_wall_actions(self);
+            events.extend(self.apply_actions(actions));
         }
 
-        let overlay = Overlay::new();
-        overlay.render(self, context, sprites, zone);
+        if let Some(player) = self.get_tiles_with_entity::<Player>().first() {
+            self.player_position = player.get_position();
+        }
 
-        if self.frame % 2 == 0 {
-            if let Some(animation) = &mut self.animation.clone() {
-                if let Some(_) = animation.update() {
-                    animation.render(self, context, sprites, zone);
-                    self.animation = Some(animation.clone());
-                } else {
-                    self.animation = None;
-                }
+        if self.is_scan_frame() {
+            if !scan_order {
+                let actions = Diamond::get_diamond_actions(self);
+                events.extend(self.apply_actions(actions));
             }
+
+            let mut finished = vec![];
+            self.animations.retain_mut(|animation| {
+                if animation.update().is_some() {
+                    return true;
+                }
+                finished.push(Event::TileChanged(animation.get_position()));
+                false
+            });
+            events.extend(finished);
         }
 
         self.increment_frame();
         self.increment_timer();
+        events.extend(self.update_magic_wall());
+
+        if self.timer <= 0.0 && !self.out_of_time && !self.is_game_over() {
+            self.out_of_time = true;
+            let action = Action::new(self.player_position, Field::Empty, ActionType::KillPlayer);
+            events.extend(self.apply_actions(vec![action]));
+        }
+
+        if self.is_game_over() {
+            if self.restart_delay > 0 {
+                self.restart_delay -= 1;
+            } else {
+                events.push(Event::GameOver);
+            }
+        }
+        events
     }
 
-    pub fn apply_actions(
-        &mut self,
-        actions: Vec<Action>,
-        context: &mut CanvasRenderingContext2d,
-        audio_context: &mut AudioContext,
-        sprites: &HtmlImageElement,
-        sounds: &Vec<AudioBuffer>,
-    ) {
-        for action in actions {
-            action.apply(self);
-            if let Some(zone) =
-                Zone::get_current_zone(self.player_position.0, self.player_position.1, &self.zones)
-            {
-                if zone.is_in_zone(action.get_position().0, action.get_position().1)
-                    && self.scroller.is_none()
-                {
-                    action.render(self, context, sprites, zone);
+    pub fn scan(&mut self) -> Vec<Event> {
+        let mut events = Vec::new();
+        let mut scanned = vec![vec![false; self.width as usize]; self.height as usize];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if scanned[y as usize][x as usize] {
+                    continue;
+                }
+                let Some(Field::Entity(entity)) =
+                    self.get_tile(x, y).and_then(|tile| tile.get_object_on())
+                else {
+                    continue;
+                };
+                let entity = Rc::clone(entity);
+                let actions = entity.update(self);
+                for action in &actions {
+                    let (ax, ay) = action.get_position();
+                    if let Some(flag) = scanned
+                        .get_mut(ay as usize)
+                        .and_then(|row| row.get_mut(ax as usize))
+                    {
+                        *flag = true;
+                    }
+                }
+                if entity.get_type().as_str() == "Player" {
+                    self.set_last_frame_direction_afk_if_needed(&actions);
+                    events.extend(self.apply_actions(actions));
+                    if let Some(player) = self.get_tiles_with_entity::<Player>().first() {
+                        self.player_position = player.get_position();
+                    }
+                } else {
+                    events.extend(self.apply_actions(actions));
                 }
-                self.play_action_sound(audio_context, action.get_action_type(), sounds);
             }
         }
+        events
     }
 
-    pub fn play_action_sound(
-        &self,
-        audio_context: &AudioContext,
-        action_type: &ActionType,
-        sounds: &Vec<AudioBuffer>,
-    ) {
-        if let Some(audio_buffer) = action_type.get_linked_sound(&sounds) {
-            let source = audio_context.create_buffer_source().unwrap();
-            source.set_buffer(Some(audio_buffer));
-            source
-                .connect_with_audio_node(&audio_context.destination())
-                .unwrap();
-            source.set_loop(false);
-            source.start().unwrap();
-        }
-    }
-
-    pub fn scroll_if_needed(
-        &mut self,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-        zones: &Vec<Zone>,
-    ) {
-        if let Some(scroller) = &mut self.scroller {
-            if let Some(active_zone) = scroller.update() {
-                active_zone.render(self, context, sprites, zone);
-            } else {
-                self.scroller = None;
-                if let Some(new_zone) =
-                    Zone::get_current_zone(self.player_position.0, self.player_position.1, &zones)
-                {
-                    new_zone.render(self, context, sprites, zone);
+    pub fn update_enemies(&mut self, kind: EnemyKind) -> Vec<Event> {
+        let mut events = Vec::new();
+        let mut touched: Vec<(i32, i32)> = Vec::new();
+        for (x, y) in Enemy::get_enemy_positions(self, kind) {
+            if touched.contains(&(x, y)) {
+                continue;
+            }
+            let Some(Field::Entity(entity)) =
+                self.get_tile(x, y).and_then(|tile| tile.get_object_on())
+            else {
+                continue;
+            };
+            let entity = Rc::clone(entity);
+            if entity.as_any().downcast_ref::<Enemy>().is_none() {
+                continue;
+            }
+            let actions = entity.update(self);
+            touched.extend(actions.iter().map(|action| action.get_position()));
+            events.extend(self.apply_actions(actions));
+        }
+        events
+    }
+
+    pub fn tally_time_bonus(&mut self) -> Vec<Event> {
+        if self.timer >= 1.0 {
+            self.timer -= 1.0;
+            self.score += 1;
+            vec![Event::PlaySound(Sound::TimeBonus)]
+        } else {
+            self.timer = 0.0;
+            vec![Event::LevelCompleted]
+        }
+    }
+
+    pub fn apply_actions(&mut self, actions: Vec<Action>) -> Vec<Event> {
+        let mut events = Vec::new();
+        for action in actions {
+            action.apply(self);
+            self.animations.extend(Animation::from_action(&action));
+            match action.get_action_type() {
+                ActionType::ClaimDiamond => {
+                    self.score += if self.diamonds_claimed < self.diamonds_number {
+                        self.diamond_value
+                    } else {
+                        self.extra_diamond_value
+                    };
+                    self.diamonds_claimed += 1;
+                    if self.diamonds_claimed == self.diamonds_number {
+                        events.push(Event::ExitOpened);
+                        events.push(Event::PlaySound(Sound::ExitOpen));
+                        for position in self.get_exit_positions() {
+                            events.push(Event::TileChanged(position));
+                        }
+                    }
                 }
+                ActionType::CollectBomb => self.bombs_held += 1,
+                ActionType::DropBomb => self.bombs_held -= 1,
+                ActionType::MagicWallActivate => self.activate_magic_wall(),
+                _ => {}
+            }
+            events.push(Event::TileChanged(action.get_position()));
+            if let Some(sound) = action.get_action_type().get_linked_sound() {
+                events.push(Event::PlaySound(sound));
             }
         }
+        events
     }
 
-    pub fn set_last_frame_direction_afk_if_needed(&mut self, actions: &Vec<Action>) {
-        if let Some(action) = actions.get(0) {
+    pub fn set_last_frame_direction_afk_if_needed(&mut self, actions: &[Action]) {
+        if let Some(action) = actions.first() {
             if action.get_position() == self.player_position && actions.len() == 1 {
                 self.last_frame_direction = Movement::Afk;
             }
         }
     }
 
-    pub fn set_scroller_if_needed(&mut self, zone: &Zone) {
-        if let Some(current_zone) =
-            Zone::get_current_zone(self.player_position.0, self.player_position.1, &self.zones)
-        {
-            if zone != current_zone {
-                if let Some(scroller) = &self.scroller {
-                    if let Some(active_zone) = scroller.get_active_zone() {
-                        self.scroller = Some(Scroller::new(active_zone, *current_zone));
-                    }
+    pub fn get_explosion_actions(
+        &self,
+        (cx, cy): (i32, i32),
+        action_type: ActionType,
+    ) -> Vec<Action> {
+        let mut actions = Vec::new();
+        for y in cy - 1..=cy + 1 {
+            for x in cx - 1..=cx + 1 {
+                match self.get_tile(x, y).map(|tile| tile.get_object_on()) {
+                    None | Some(Some(Field::Exit)) => continue,
+                    Some(Some(Field::Wall(wall))) if !wall.is_explodable() => continue,
+                    _ => {}
+                }
+                let field = match action_type {
+                    ActionType::ExplodeToDiamond => Field::Entity(Rc::new(Diamond::new(x, y))),
+                    _ => Field::Empty,
+                };
+                if self.player_position == (x, y) && !self.is_game_over() {
+                    actions.push(Action::new((x, y), field, ActionType::KillPlayer));
                 } else {
-                    self.scroller = Some(Scroller::new(*zone, *current_zone));
+                    actions.push(Action::new((x, y), field, action_type));
                 }
             }
         }
+        actions
     }
 
-    pub fn render_diamonds_gif(
-        &self,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
-        for diamond in self.get_tiles_with_entity::<diamond::Diamond>() {
-            if self.scroller.is_none()
-                && zone.is_in_zone(diamond.get_position().0, diamond.get_position().1)
-            {
-                diamond.render(self, context, sprites, zone);
+    pub fn activate_magic_wall(&mut self) {
+        if self.magic_wall_state == MagicWallState::Dormant {
+            self.magic_wall_state = MagicWallState::Active(self.magic_wall_time);
+        }
+    }
+
+    pub fn update_magic_wall(&mut self) -> Vec<Event> {
+        let mut events = Vec::new();
+        if let MagicWallState::Active(remaining) = self.magic_wall_state {
+            if remaining > 0.0 {
+                self.magic_wall_state = MagicWallState::Active(remaining - 0.05);
+            } else {
+                self.magic_wall_state = MagicWallState::Expired;
+                for magic_wall in self.get_magic_walls() {
+                    events.push(Event::TileChanged(magic_wall.get_position()));
+                }
             }
         }
+        events
+    }
+
+    pub fn update_slimes(&mut self) {
+        let mut random = self.random;
+        self.open_slimes = self
+            .get_slimes()
+            .into_iter()
+            .filter(|_| random.next_u8() < self.slime_permeability)
+            .map(|slime| slime.get_position())
+            .collect();
+        self.random = random;
+    }
+
+    pub fn is_slime_open(&self, position: (i32, i32)) -> bool {
+        self.open_slimes.contains(&position)
+    }
+
+    pub fn is_scan_frame(&self) -> bool {
+        self.frame % 2 == 0
     }
 
     pub fn increment_frame(&mut self) {
@@ -292,8 +457,12 @@ impl Grid {
     }
 
     pub fn increment_timer(&mut self) {
+        self.elapsed += 0.05;
         if self.timer > 0.0 {
-            self.timer -= 0.05;
+            self.timer = (self.timer - 0.05).max(0.0);
+        }
+        if let Some(time) = self.amoeba_time {
+            self.amoeba_time = Some((time - 0.05).max(0.0));
         }
     }
 
@@ -341,6 +510,69 @@ impl Grid {
         concerned_tiles
     }
 
+    pub fn get_amoebas(&self) -> Vec<&Amoeba> {
+        let mut amoebas = vec![];
+        for row in &self.tiles {
+            for tile in row {
+                if let Some(Field::Amoeba(amoeba)) = tile.get_object_on() {
+                    amoebas.push(amoeba);
+                }
+            }
+        }
+        amoebas
+    }
+
+    pub fn get_walls(&self) -> Vec<&Wall> {
+        let mut walls = vec![];
+        for row in &self.tiles {
+            for tile in row {
+                if let Some(Field::Wall(wall)) = tile.get_object_on() {
+                    walls.push(wall);
+                }
+            }
+        }
+        walls
+    }
+
+    pub fn get_magic_walls(&self) -> Vec<&MagicWall> {
+        let mut magic_walls = vec![];
+        for row in &self.tiles {
+            for tile in row {
+                if let Some(Field::MagicWall(magic_wall)) = tile.get_object_on() {
+                    magic_walls.push(magic_wall);
+                }
+            }
+        }
+        magic_walls
+    }
+
+    pub fn get_slimes(&self) -> Vec<&Slime> {
+        let mut slimes = vec![];
+        for row in &self.tiles {
+            for tile in row {
+                if let Some(Field::Slime(slime)) = tile.get_object_on() {
+                    slimes.push(slime);
+                }
+            }
+        }
+        slimes
+    }
+
+    pub fn get_magic_wall_state(&self) -> MagicWallState {
+        self.magic_wall_state
+    }
+
+    pub fn get_amoeba_max_size(&self) -> i32 {
+        self.amoeba_max_size
+    }
+
+    pub fn get_amoeba_growth_rate(&self) -> u8 {
+        match self.amoeba_time {
+            Some(time) if time <= 0.0 => AMOEBA_FAST_GROWTH_RATE,
+            _ => self.amoeba_growth_rate,
+        }
+    }
+
     pub fn get_frame(&self) -> i32 {
         self.frame
     }
@@ -353,24 +585,72 @@ impl Grid {
         self.diamonds_number
     }
 
+    pub fn get_diamonds_claimed(&self) -> i32 {
+        self.diamonds_claimed
+    }
+
+    pub fn get_bombs_held(&self) -> i32 {
+        self.bombs_held
+    }
+
+    pub fn get_current_diamond_value(&self) -> i32 {
+        if self.is_exit_open() {
+            self.extra_diamond_value
+        } else {
+            self.diamond_value
+        }
+    }
+
+    pub fn is_exit_open(&self) -> bool {
+        self.diamonds_claimed >= self.diamonds_number
+    }
+
+    pub fn get_exit_positions(&self) -> Vec<(i32, i32)> {
+        let mut positions = vec![];
+        for row in &self.tiles {
+            for tile in row {
+                if let Some(Field::Exit) = tile.get_object_on() {
+                    positions.push(tile.get_position());
+                }
+            }
+        }
+        positions
+    }
+
     pub fn get_timer(&self) -> f64 {
         self.timer
     }
 
-    pub fn render_player_zone(
-        &mut self,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-    ) {
-        if let Some(zone) =
-            Zone::get_current_zone(self.player_position.0, self.player_position.1, &self.zones)
-        {
-            zone.render(self, context, sprites, &zone);
-        }
+    pub fn get_elapsed_time(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn get_score(&self) -> i32 {
+        self.score
+    }
+
+    pub fn is_out_of_time(&self) -> bool {
+        self.out_of_time
+    }
+
+    pub fn get_animations(&self) -> &[Animation] {
+        &self.animations
+    }
+
+    pub fn get_width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn get_height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn is_intermission(&self) -> bool {
+        self.intermission
     }
 
     pub fn is_game_over(&self) -> bool {
-        self.get_tiles_with_entity::<Player>().len() == 0
+        self.get_tiles_with_entity::<Player>().is_empty()
     }
 
     pub fn is_level_completed(&self) -> bool {
@@ -385,10 +665,16 @@ impl Grid {
     }
 
     pub fn set_player_doing(&mut self, movement: Movement) {
-        if self.animation.is_some() {
+        if self
+            .animations
+            .iter()
+            .any(|animation| animation.is_blocking_input())
+        {
             return;
         };
-        if movement == Movement::MoveLeft || movement == Movement::MoveRight {
+        if movement.is_snap() {
+            self.last_frame_direction = movement;
+        } else if movement == Movement::MoveLeft || movement == Movement::MoveRight {
             self.last_frame_direction = movement;
             self.last_frame_side_direction = movement;
         } else {
@@ -401,10 +687,9 @@ impl Grid {
                 if let Some(player) = entity.as_any().downcast_ref::<Player>() {
                     let mut clone_player = player.clone();
                     clone_player.set_movement(movement);
-                    let action: Action;
 
                     let field = Field::Entity(Rc::new(clone_player));
-                    action = Action::new((x, y), field, ActionType::PlayerSetMovement);
+                    let action = Action::new((x, y), field, ActionType::PlayerSetMovement);
 
                     action.apply(self);
                 };
@@ -412,3 +697,11 @@ impl Grid {
         };
     }
 }
+
+impl FromStr for Grid {
+    type Err = LevelParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Grid::new(&input.parse()?))
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::enums::{input_action::InputAction, movement::Movement};
+
+const SWIPE_DEAD_ZONE: f64 = 20.0;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(
+    from = "BTreeMap<String, InputAction>",
+    into = "BTreeMap<String, InputAction>"
+)]
+pub struct InputBindings {
+    bindings: BTreeMap<String, InputAction>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        let mut bindings = InputBindings {
+            bindings: BTreeMap::new(),
+        };
+        for (keys, action) in [
+            (
+                &["ArrowUp", "w", "z", "GamepadUp"][..],
+                InputAction::Move(Movement::MoveUp),
+            ),
+            (
+                &["ArrowDown", "s", "GamepadDown"],
+                InputAction::Move(Movement::MoveDown),
+            ),
+            (
+                &["ArrowLeft", "a", "q", "GamepadLeft"],
+                InputAction::Move(Movement::MoveLeft),
+            ),
+            (
+                &["ArrowRight", "d", "GamepadRight"],
+                InputAction::Move(Movement::MoveRight),
+            ),
+            (&["Control", " ", "GamepadA"], InputAction::Snap),
+            (&["p", "Pause", "GamepadStart"], InputAction::Pause),
+        ] {
+            for key in keys {
+                bindings.bind(key, action);
+            }
+        }
+        bindings.bind("b", InputAction::Bomb);
+        bindings.bind("GamepadB", InputAction::Bomb);
+        bindings.bind("Escape", InputAction::Restart);
+        bindings.bind("GamepadSelect", InputAction::Restart);
+        bindings.bind("Backspace", InputAction::Quit);
+        bindings
+    }
+}
+
+impl From<BTreeMap<String, InputAction>> for InputBindings {
+    fn from(bindings: BTreeMap<String, InputAction>) -> Self {
+        let mut bindings = InputBindings { bindings };
+        let defaults = InputBindings::default();
+        for action in InputAction::BINDABLE {
+            if bindings.get_keys(action).is_empty() {
+                for key in defaults.get_keys(action) {
+                    if !bindings.bindings.contains_key(key) {
+                        bindings.bind(key, action);
+                    }
+                }
+            }
+        }
+        bindings
+    }
+}
+
+impl From<InputBindings> for BTreeMap<String, InputAction> {
+    fn from(bindings: InputBindings) -> Self {
+        bindings.bindings
+    }
+}
+
+impl InputBindings {
+    pub fn normalize(key: &str) -> String {
+        if key.chars().count() == 1 {
+            key.to_lowercase()
+        } else {
+            key.to_string()
+        }
+    }
+
+    pub fn get_action(&self, key: &str) -> Option<InputAction> {
+        self.bindings.get(&InputBindings::normalize(key)).copied()
+    }
+
+    pub fn get_keys(&self, action: InputAction) -> Vec<&str> {
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| **bound == action)
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    pub fn bind(&mut self, key: &str, action: InputAction) {
+        self.bindings.insert(InputBindings::normalize(key), action);
+    }
+
+    pub fn rebind(&mut self, key: &str, action: InputAction) -> Option<InputAction> {
+        let displaced = self.get_action(key).filter(|bound| *bound != action);
+        self.bindings
+            .retain(|bound_key, bound| *bound != action || bound_key.starts_with("Gamepad"));
+        self.bind(key, action);
+        displaced
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InputQueue {
+    held: Vec<Movement>,
+    latched: Option<Movement>,
+    snap: u32,
+    bomb: bool,
+}
+
+impl InputQueue {
+    pub fn new() -> Self {
+        InputQueue::default()
+    }
+
+    pub fn press(&mut self, direction: Movement) {
+        self.held.push(direction);
+        self.latched = Some(self.apply_snap(direction));
+    }
+
+    pub fn release(&mut self, direction: Movement) {
+        if let Some(index) = self.held.iter().rposition(|held| *held == direction) {
+            self.held.remove(index);
+        }
+    }
+
+    pub fn press_snap(&mut self) {
+        self.snap += 1;
+    }
+
+    pub fn release_snap(&mut self) {
+        self.snap = self.snap.saturating_sub(1);
+    }
+
+    pub fn press_bomb(&mut self) {
+        self.bomb = true;
+    }
+
+    pub fn clear(&mut self) {
+        *self = InputQueue::new();
+    }
+
+    pub fn take_movement(&mut self) -> Movement {
+        if std::mem::take(&mut self.bomb) {
+            return Movement::DropBomb;
+        }
+        match self.latched.take() {
+            Some(movement) => movement,
+            None => self
+                .held
+                .last()
+                .map_or(Movement::Afk, |direction| self.apply_snap(*direction)),
+        }
+    }
+
+    fn apply_snap(&self, direction: Movement) -> Movement {
+        if self.snap > 0 {
+            direction.snap()
+        } else {
+            direction
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TouchInput {
+    origin: Option<(f64, f64)>,
+    position: Option<(f64, f64)>,
+    snap: bool,
+}
+
+impl TouchInput {
+    pub fn new() -> Self {
+        TouchInput::default()
+    }
+
+    pub fn start(&mut self, x: f64, y: f64, touches: u32) {
+        if touches > 1 && self.origin.is_some() {
+            self.snap = true;
+            return;
+        }
+        self.origin = Some((x, y));
+        self.position = Some((x, y));
+    }
+
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        if self.origin.is_some() {
+            self.position = Some((x, y));
+        }
+    }
+
+    pub fn end(&mut self, touches: u32) -> Option<Movement> {
+        if touches > 0 {
+            self.snap = false;
+            return None;
+        }
+        let movement = self.origin.map(|_| self.get_movement());
+        *self = TouchInput::new();
+        movement
+    }
+
+    pub fn is_snapping(&self) -> bool {
+        self.snap
+    }
+
+    pub fn get_movement(&self) -> Movement {
+        let (Some((start_x, start_y)), Some((x, y))) = (self.origin, self.position) else {
+            return Movement::Afk;
+        };
+        let (delta_x, delta_y) = (x - start_x, y - start_y);
+        if delta_x.abs() < SWIPE_DEAD_ZONE && delta_y.abs() < SWIPE_DEAD_ZONE {
+            Movement::Afk
+        } else if delta_x.abs() > delta_y.abs() {
+            if delta_x > 0.0 {
+                Movement::MoveRight
+            } else {
+                Movement::MoveLeft
+            }
+        } else if delta_y > 0.0 {
+            Movement::MoveDown
+        } else {
+            Movement::MoveUp
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use std::any::Any;
 use std::fmt;
+use std::rc::Rc;
 
-use crate::game::{display::action::Action, grid::Grid};
+use crate::game::{display::action::Action, enums::entity_state::EntityState, grid::Grid};
 
 use super::{collidable::Collidable, movable::Movable, renderable::Renderable};
 
@@ -10,6 +11,14 @@ pub trait Entity: Movable + Collidable + Renderable {
     fn as_any(&self) -> &dyn Any;
     fn update(&self, grid: &Grid) -> Vec<Action>;
 
+    fn get_state(&self) -> Option<EntityState> {
+        None
+    }
+
+    fn restore_state(&self, _: EntityState) -> Option<Rc<dyn Entity>> {
+        None
+    }
+
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
 }
 
@@ -0,0 +1,6 @@
+use crate::game::{display::action::Action, grid::Grid};
+
+pub trait Explodable {
+    fn explode(&self, grid: &Grid) -> Vec<Action>;
+    fn is_touching_player(&self, grid: &Grid) -> bool;
+}
@@ -5,5 +5,5 @@ use super::super::enums::movement::Movement;
 pub trait Fallable {
     fn fall(&self, grid: &Grid) -> Vec<Action>;
     fn is_falling(&self, grid: &Grid) -> Option<Movement>;
-    fn is_fallable_near(&self, grid: &Grid) -> bool;
+    fn is_on_rounded(&self, grid: &Grid) -> bool;
 }
@@ -1,5 +1,7 @@
 pub mod collidable;
 pub mod entity;
+pub mod explodable;
 pub mod fallable;
 pub mod movable;
 pub mod renderable;
+pub mod storage;
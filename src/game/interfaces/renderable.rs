@@ -1,13 +1,5 @@
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
-
-use crate::game::{display::zone::Zone, grid::Grid};
+use crate::game::grid::Grid;
 
 pub trait Renderable {
-    fn render(
-        &self,
-        grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    );
+    fn get_sprite_position(&self, grid: &Grid) -> (f64, f64);
 }
@@ -0,0 +1,215 @@
+use std::str::FromStr;
+
+use crate::game::enums::engine_mode::EngineMode;
+
+use super::{
+    definition::{LevelDefinition, LevelHeader},
+    error::{LevelParseError, LevelParseErrorKind},
+};
+
+pub fn is_bdcff(input: &str) -> bool {
+    input.trim_start().starts_with("[BDCFF]")
+}
+
+pub fn get_engine_char(code: char) -> Option<char> {
+    match code {
+        ' ' => Some(' '),
+        '.' => Some('.'),
+        'W' => Some('W'),
+        'w' | 'x' | 'v' | 's' => Some(code),
+        'm' | 'M' => Some('M'),
+        'r' => Some('r'),
+        'd' => Some('d'),
+        'P' => Some('P'),
+        'X' | 'H' => Some('X'),
+        'q' | 'Q' | 'o' | 'O' => Some('q'),
+        'B' | 'b' | 'c' | 'C' => Some('B'),
+        'a' => Some('a'),
+        _ => None,
+    }
+}
+
+fn pick_value<T: FromStr>(
+    value: &str,
+    difficulty: usize,
+    key: &str,
+    (line, column): (usize, usize),
+) -> Result<T, LevelParseError> {
+    let values: Vec<&str> = value.split_whitespace().collect();
+    let index = difficulty
+        .saturating_sub(1)
+        .min(values.len().saturating_sub(1));
+    values
+        .get(index)
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| {
+            LevelParseError::new(
+                line,
+                column,
+                LevelParseErrorKind::InvalidValue(key.to_string()),
+            )
+        })
+}
+
+fn apply_property(
+    header: &mut LevelHeader,
+    key: &str,
+    value: &str,
+    difficulty: usize,
+    position: (usize, usize),
+) -> Result<(), LevelParseError> {
+    match key.to_lowercase().as_str() {
+        "name" => header.set_name(value),
+        "author" => header.set_author(value),
+        "cavetime" => header.set_cave_time(pick_value(value, difficulty, key, position)?),
+        "diamondsrequired" => {
+            header.set_diamonds_required(pick_value(value, difficulty, key, position)?)
+        }
+        "diamondvalue" => {
+            let diamond_value = pick_value(value, 1, key, position)?;
+            header.set_diamond_value(diamond_value);
+            header.set_extra_diamond_value(pick_value(value, 2, key, position)?);
+        }
+        "magicwalltime" => {
+            header.set_magic_wall_time(pick_value(value, difficulty, key, position)?)
+        }
+        "amoebatime" => header.set_amoeba_time(pick_value(value, difficulty, key, position)?),
+        "amoebathreshold" => {
+            header.set_amoeba_max_size(pick_value(value, difficulty, key, position)?)
+        }
+        "slimepermeability" => {
+            let permeability: f64 = pick_value(value, difficulty, key, position)?;
+            header.set_slime_permeability((permeability * 256.0).clamp(0.0, 255.0) as u8)
+        }
+        "randseed" => header.set_seed(pick_value(value, difficulty, key, position)?),
+        "intermission" => header.set_intermission(value.eq_ignore_ascii_case("true")),
+        "engine" => header.set_engine(get_engine_mode(value).ok_or_else(|| {
+            LevelParseError::new(
+                position.0,
+                position.1,
+                LevelParseErrorKind::InvalidValue(key.to_string()),
+            )
+        })?),
+        _ => {}
+    }
+    Ok(())
+}
+
+pub fn get_engine_mode(value: &str) -> Option<EngineMode> {
+    match value.trim().to_lowercase().as_str() {
+        "bd1" | "bd2" | "plck" | "1stb" | "crdr" | "crli" => Some(EngineMode::ScanOrder),
+        "passes" => Some(EngineMode::Passes),
+        _ => None,
+    }
+}
+
+pub fn parse_bdcff(
+    input: &str,
+    difficulty: usize,
+) -> Result<Vec<LevelDefinition>, LevelParseError> {
+    let mut levels = Vec::new();
+    let mut game_header = LevelHeader::default();
+    let mut cave: Option<(usize, LevelHeader)> = None;
+    let mut map: Option<Vec<Vec<char>>> = None;
+    let mut in_map = false;
+    let mut skipped_section: Option<String> = None;
+    let mut objects: Option<usize> = None;
+    let mut line_number = 0;
+
+    for (index, line) in input.lines().enumerate() {
+        line_number = index + 1;
+        let trimmed = line.trim();
+
+        if let Some(section) = &skipped_section {
+            if trimmed.eq_ignore_ascii_case(&format!("[/{}]", section)) {
+                skipped_section = None;
+            }
+            continue;
+        }
+
+        if in_map {
+            if trimmed.eq_ignore_ascii_case("[/map]") {
+                in_map = false;
+                continue;
+            }
+            let mut row = Vec::new();
+            for (x, code) in line.trim_end_matches('\r').chars().enumerate() {
+                let ch = get_engine_char(code).ok_or_else(|| {
+                    LevelParseError::new(line_number, x + 1, LevelParseErrorKind::UnknownTile(code))
+                })?;
+                row.push(ch);
+            }
+            map.get_or_insert_with(Vec::new).push(row);
+            continue;
+        }
+
+        if trimmed.is_empty() || trimmed.starts_with(';') {
+            continue;
+        }
+
+        if let Some(section) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            match section.to_lowercase().as_str() {
+                "bdcff" | "/bdcff" | "game" | "/game" => {}
+                "cave" => {
+                    let mut header = game_header.clone();
+                    header.set_name(&format!("Cave {}", levels.len() + 1));
+                    cave = Some((line_number, header));
+                    map = None;
+                    objects = None;
+                }
+                "/cave" => {
+                    if let Some((start, header)) = cave.take() {
+                        let rows = map.take().ok_or_else(|| match objects {
+                            Some(line) => LevelParseError::new(
+                                line,
+                                1,
+                                LevelParseErrorKind::UnsupportedSection("objects".to_string()),
+                            ),
+                            None => LevelParseError::new(start, 1, LevelParseErrorKind::MissingMap),
+                        })?;
+                        levels.push(LevelDefinition::new(header, rows));
+                    }
+                }
+                "map" => in_map = true,
+                "objects" => {
+                    objects = Some(line_number);
+                    skipped_section = Some("objects".to_string());
+                }
+                other if !other.starts_with('/') => skipped_section = Some(other.to_string()),
+                _ => {}
+            }
+            continue;
+        }
+
+        if let Some((key, value)) = trimmed.split_once('=') {
+            let column = line.find('=').unwrap_or(0) + 2;
+            let header = match &mut cave {
+                Some((_, header)) => header,
+                None => &mut game_header,
+            };
+            apply_property(
+                header,
+                key.trim(),
+                value.trim(),
+                difficulty,
+                (line_number, column),
+            )?;
+        }
+    }
+
+    if in_map {
+        return Err(LevelParseError::new(
+            line_number,
+            1,
+            LevelParseErrorKind::UnterminatedSection("map".to_string()),
+        ));
+    }
+    if let Some((start, _)) = cave {
+        return Err(LevelParseError::new(
+            start,
+            1,
+            LevelParseErrorKind::UnterminatedSection("cave".to_string()),
+        ));
+    }
+    Ok(levels)
+}
@@ -0,0 +1,374 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::enums::{engine_mode::EngineMode, field::Field};
+
+use super::error::{LevelParseError, LevelParseErrorKind};
+
+pub const LEVEL_FORMAT_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LevelHeader {
+    version: u32,
+    name: String,
+    author: String,
+    cave_time: f64,
+    diamonds_required: i32,
+    diamond_value: i32,
+    extra_diamond_value: i32,
+    amoeba_max_size: i32,
+    amoeba_growth_rate: u8,
+    amoeba_time: f64,
+    magic_wall_time: f64,
+    slime_permeability: u8,
+    seed: u8,
+    intermission: bool,
+    engine: EngineMode,
+}
+
+impl Default for LevelHeader {
+    fn default() -> Self {
+        LevelHeader {
+            version: LEVEL_FORMAT_VERSION,
+            name: String::new(),
+            author: String::new(),
+            cave_time: 150.0,
+            diamonds_required: 0,
+            diamond_value: 10,
+            extra_diamond_value: 15,
+            amoeba_max_size: 200,
+            amoeba_growth_rate: 8,
+            amoeba_time: 0.0,
+            magic_wall_time: 20.0,
+            slime_permeability: 255,
+            seed: 0,
+            intermission: false,
+            engine: EngineMode::Passes,
+        }
+    }
+}
+
+impl LevelHeader {
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn get_cave_time(&self) -> f64 {
+        self.cave_time
+    }
+
+    pub fn get_diamonds_required(&self) -> i32 {
+        self.diamonds_required
+    }
+
+    pub fn get_diamond_value(&self) -> i32 {
+        self.diamond_value
+    }
+
+    pub fn get_extra_diamond_value(&self) -> i32 {
+        self.extra_diamond_value
+    }
+
+    pub fn get_amoeba_max_size(&self) -> i32 {
+        self.amoeba_max_size
+    }
+
+    pub fn get_amoeba_growth_rate(&self) -> u8 {
+        self.amoeba_growth_rate
+    }
+
+    pub fn get_amoeba_time(&self) -> f64 {
+        self.amoeba_time
+    }
+
+    pub fn get_magic_wall_time(&self) -> f64 {
+        self.magic_wall_time
+    }
+
+    pub fn get_slime_permeability(&self) -> u8 {
+        self.slime_permeability
+    }
+
+    pub fn get_seed(&self) -> u8 {
+        self.seed
+    }
+
+    pub fn is_intermission(&self) -> bool {
+        self.intermission
+    }
+
+    pub fn get_engine(&self) -> EngineMode {
+        self.engine
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    pub fn set_author(&mut self, author: &str) {
+        self.author = author.to_string();
+    }
+
+    pub fn set_cave_time(&mut self, cave_time: f64) {
+        self.cave_time = cave_time;
+    }
+
+    pub fn set_diamonds_required(&mut self, diamonds_required: i32) {
+        self.diamonds_required = diamonds_required;
+    }
+
+    pub fn set_diamond_value(&mut self, diamond_value: i32) {
+        self.diamond_value = diamond_value;
+    }
+
+    pub fn set_extra_diamond_value(&mut self, extra_diamond_value: i32) {
+        self.extra_diamond_value = extra_diamond_value;
+    }
+
+    pub fn set_amoeba_max_size(&mut self, amoeba_max_size: i32) {
+        self.amoeba_max_size = amoeba_max_size;
+    }
+
+    pub fn set_amoeba_time(&mut self, amoeba_time: f64) {
+        self.amoeba_time = amoeba_time;
+    }
+
+    pub fn set_magic_wall_time(&mut self, magic_wall_time: f64) {
+        self.magic_wall_time = magic_wall_time;
+    }
+
+    pub fn set_slime_permeability(&mut self, slime_permeability: u8) {
+        self.slime_permeability = slime_permeability;
+    }
+
+    pub fn set_seed(&mut self, seed: u8) {
+        self.seed = seed;
+    }
+
+    pub fn set_intermission(&mut self, intermission: bool) {
+        self.intermission = intermission;
+    }
+
+    pub fn set_engine(&mut self, engine: EngineMode) {
+        self.engine = engine;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelDefinition {
+    header: LevelHeader,
+    width: i32,
+    height: i32,
+    declared_player_position: Option<(i32, i32)>,
+    map: Vec<Vec<char>>,
+}
+
+impl LevelDefinition {
+    pub fn new(header: LevelHeader, map: Vec<Vec<char>>) -> Self {
+        LevelDefinition {
+            header,
+            width: map.first().map_or(0, |row| row.len() as i32),
+            height: map.len() as i32,
+            declared_player_position: None,
+            map,
+        }
+    }
+
+    pub fn get_header(&self) -> &LevelHeader {
+        &self.header
+    }
+
+    pub fn get_header_mut(&mut self) -> &mut LevelHeader {
+        &mut self.header
+    }
+
+    pub fn get_width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn get_height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn get_declared_player_position(&self) -> Option<(i32, i32)> {
+        self.declared_player_position
+    }
+
+    pub fn get_map(&self) -> &[Vec<char>] {
+        &self.map
+    }
+
+    pub fn get_char(&self, x: i32, y: i32) -> Option<char> {
+        self.map
+            .get(usize::try_from(y).ok()?)
+            .and_then(|row| row.get(usize::try_from(x).ok()?))
+            .copied()
+    }
+
+    pub fn set_char(&mut self, x: i32, y: i32, ch: char) -> bool {
+        let Some(tile) = usize::try_from(y)
+            .ok()
+            .and_then(|y| self.map.get_mut(y))
+            .and_then(|row| row.get_mut(usize::try_from(x).ok()?))
+        else {
+            return false;
+        };
+        if *tile == ch {
+            return false;
+        }
+        *tile = ch;
+        true
+    }
+
+    pub fn to_level_string(&self) -> String {
+        let mut text = toml::to_string(&self.header).expect("Could not serialize level header");
+        text.push_str("-\n");
+        for row in &self.map {
+            text.extend(row);
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn find_chars(&self, wanted: char) -> Vec<(i32, i32)> {
+        let mut positions = vec![];
+        for (y, row) in self.map.iter().enumerate() {
+            for (x, ch) in row.iter().enumerate() {
+                if *ch == wanted {
+                    positions.push((x as i32, y as i32));
+                }
+            }
+        }
+        positions
+    }
+
+    pub fn get_player_position(&self) -> Option<(i32, i32)> {
+        self.find_chars('P')
+            .first()
+            .copied()
+            .or(self.declared_player_position)
+    }
+
+    fn parse_numbers(line: &str) -> Option<Vec<i32>> {
+        line.split_whitespace()
+            .map(|part| part.parse().ok())
+            .collect()
+    }
+
+    fn parse_legacy_line(
+        lines: &[&str],
+        index: usize,
+        field: &str,
+        count: usize,
+    ) -> Result<Vec<i32>, LevelParseError> {
+        lines
+            .get(index)
+            .and_then(|line| LevelDefinition::parse_numbers(line))
+            .filter(|numbers| numbers.len() == count)
+            .ok_or_else(|| {
+                LevelParseError::new(
+                    index + 1,
+                    1,
+                    LevelParseErrorKind::InvalidLegacyHeader(field.to_string()),
+                )
+            })
+    }
+
+    fn parse_header(lines: &[&str], first_line: usize) -> Result<LevelHeader, LevelParseError> {
+        let text = lines[first_line..].join("\n");
+        let header: LevelHeader = toml::from_str(&text).map_err(|error| {
+            let offset = error.span().map_or(0, |span| span.start);
+            let before = &text[..offset];
+            let line = before.matches('\n').count();
+            let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1);
+            LevelParseError::new(
+                first_line + line + 1,
+                column + 1,
+                LevelParseErrorKind::InvalidHeader(error.message().to_string()),
+            )
+        })?;
+        if header.version > LEVEL_FORMAT_VERSION {
+            return Err(LevelParseError::new(
+                first_line + 1,
+                1,
+                LevelParseErrorKind::UnsupportedVersion(header.version),
+            ));
+        }
+        Ok(header)
+    }
+}
+
+impl FromStr for LevelDefinition {
+    type Err = LevelParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = input.lines().collect();
+        let separator = lines
+            .iter()
+            .position(|line| line.trim() == "-")
+            .ok_or_else(|| {
+                LevelParseError::new(lines.len().max(1), 1, LevelParseErrorKind::MissingSeparator)
+            })?;
+
+        let legacy = lines
+            .first()
+            .and_then(|line| LevelDefinition::parse_numbers(line))
+            .is_some_and(|numbers| numbers.len() == 2);
+
+        let mut declared = None;
+        let mut header_start = 0;
+        if legacy {
+            let size = LevelDefinition::parse_legacy_line(&lines, 0, "size", 2)?;
+            let player = LevelDefinition::parse_legacy_line(&lines, 1, "player", 2)?;
+            let diamonds = LevelDefinition::parse_legacy_line(&lines, 2, "diamonds", 1)?;
+            declared = Some(((size[1], size[0]), (player[0], player[1]), diamonds[0]));
+            header_start = 3;
+        }
+
+        let mut header = LevelDefinition::parse_header(&lines[..separator], header_start)?;
+
+        let mut map_lines = &lines[separator + 1..];
+        while let Some((last, rest)) = map_lines.split_last() {
+            if !last.is_empty() {
+                break;
+            }
+            map_lines = rest;
+        }
+
+        let mut map = Vec::new();
+        for (y, line) in map_lines.iter().enumerate() {
+            let mut row = Vec::new();
+            for (x, ch) in line.chars().enumerate() {
+                if Field::from_char(ch, x as i32, y as i32).is_none() {
+                    return Err(LevelParseError::new(
+                        separator + y + 2,
+                        x + 1,
+                        LevelParseErrorKind::UnknownTile(ch),
+                    ));
+                }
+                row.push(ch);
+            }
+            map.push(row);
+        }
+
+        let mut level = LevelDefinition::new(header.clone(), map);
+        if let Some(((width, height), player, diamonds)) = declared {
+            header.diamonds_required = diamonds;
+            level.header = header;
+            level.width = width;
+            level.height = height;
+            level.declared_player_position = Some(player);
+        }
+        Ok(level)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::game::enums::field::Field;
+
+use super::definition::{LevelDefinition, LevelHeader};
+
+const MAX_HISTORY: usize = 100;
+
+#[derive(Clone, Debug)]
+pub struct LevelEditor {
+    level: LevelDefinition,
+    undo_stack: Vec<LevelDefinition>,
+    redo_stack: Vec<LevelDefinition>,
+    in_stroke: bool,
+    stroke_recorded: bool,
+}
+
+impl LevelEditor {
+    pub fn new(width: i32, height: i32) -> Self {
+        let mut map = vec![vec!['.'; width as usize]; height as usize];
+        for (y, row) in map.iter_mut().enumerate() {
+            for (x, tile) in row.iter_mut().enumerate() {
+                if x == 0 || y == 0 || x == width as usize - 1 || y == height as usize - 1 {
+                    *tile = 'W';
+                }
+            }
+        }
+        map[1][1] = 'P';
+        map[height as usize - 2][width as usize - 2] = 'X';
+        LevelEditor::from_level(LevelDefinition::new(LevelHeader::default(), map))
+    }
+
+    pub fn from_level(level: LevelDefinition) -> Self {
+        LevelEditor {
+            level: LevelDefinition::new(level.get_header().clone(), level.get_map().to_vec()),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            in_stroke: false,
+            stroke_recorded: false,
+        }
+    }
+
+    pub fn get_level(&self) -> &LevelDefinition {
+        &self.level
+    }
+
+    pub fn begin_edit(&mut self) {
+        self.undo_stack.push(self.level.clone());
+        if self.undo_stack.len() > MAX_HISTORY {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    pub fn begin_stroke(&mut self) {
+        self.in_stroke = true;
+        self.stroke_recorded = false;
+    }
+
+    pub fn end_stroke(&mut self) {
+        self.in_stroke = false;
+    }
+
+    pub fn paint(&mut self, x: i32, y: i32, tile: char) -> bool {
+        if Field::from_char(tile, x, y).is_none()
+            || self
+                .level
+                .get_char(x, y)
+                .is_none_or(|current| current == tile)
+        {
+            return false;
+        }
+        if !self.in_stroke || !self.stroke_recorded {
+            self.begin_edit();
+            self.stroke_recorded = true;
+        }
+        if tile == 'P' {
+            for (px, py) in self.level.find_chars('P') {
+                if (px, py) != (x, y) {
+                    self.level.set_char(px, py, ' ');
+                }
+            }
+        }
+        self.level.set_char(x, y, tile)
+    }
+
+    pub fn set_cave_time(&mut self, cave_time: f64) -> bool {
+        let cave_time = cave_time.max(1.0);
+        if self.level.get_header().get_cave_time() == cave_time {
+            return false;
+        }
+        self.begin_edit();
+        self.level.get_header_mut().set_cave_time(cave_time);
+        true
+    }
+
+    pub fn set_diamonds_required(&mut self, diamonds_required: i32) -> bool {
+        let diamonds_required = diamonds_required.max(0);
+        if self.level.get_header().get_diamonds_required() == diamonds_required {
+            return false;
+        }
+        self.begin_edit();
+        self.level
+            .get_header_mut()
+            .set_diamonds_required(diamonds_required);
+        true
+    }
+
+    pub fn set_name(&mut self, name: &str) -> bool {
+        if self.level.get_header().get_name() == name {
+            return false;
+        }
+        self.begin_edit();
+        self.level.get_header_mut().set_name(name);
+        true
+    }
+
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop() {
+            Some(level) => {
+                self.redo_stack
+                    .push(std::mem::replace(&mut self.level, level));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(level) => {
+                self.undo_stack
+                    .push(std::mem::replace(&mut self.level, level));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    pub fn export(&self) -> String {
+        self.level.to_level_string()
+    }
+}
@@ -0,0 +1,152 @@
+use std::{error::Error, fmt};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LevelParseErrorKind {
+    MissingSeparator,
+    InvalidLegacyHeader(String),
+    InvalidHeader(String),
+    UnsupportedVersion(u32),
+    UnknownTile(char),
+    InvalidValue(String),
+    MissingMap,
+    UnterminatedSection(String),
+    UnsupportedSection(String),
+}
+
+impl fmt::Display for LevelParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LevelParseErrorKind::MissingSeparator => {
+                write!(f, "missing '-' line between the header and the map")
+            }
+            LevelParseErrorKind::InvalidLegacyHeader(field) => {
+                write!(f, "could not parse the {} line", field)
+            }
+            LevelParseErrorKind::InvalidHeader(message) => {
+                write!(f, "invalid header: {}", message.trim())
+            }
+            LevelParseErrorKind::UnsupportedVersion(version) => {
+                write!(f, "unsupported level format version {}", version)
+            }
+            LevelParseErrorKind::UnknownTile(ch) => write!(f, "unknown tile '{}'", ch),
+            LevelParseErrorKind::InvalidValue(key) => write!(f, "invalid value for {}", key),
+            LevelParseErrorKind::MissingMap => write!(f, "cave has no [map] section"),
+            LevelParseErrorKind::UnterminatedSection(section) => {
+                write!(f, "section [{}] is never closed", section)
+            }
+            LevelParseErrorKind::UnsupportedSection(section) => {
+                write!(
+                    f,
+                    "caves drawn with a [{}] section are not supported",
+                    section
+                )
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelParseError {
+    line: usize,
+    column: usize,
+    kind: LevelParseErrorKind,
+}
+
+impl LevelParseError {
+    pub fn new(line: usize, column: usize, kind: LevelParseErrorKind) -> Self {
+        LevelParseError { line, column, kind }
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    pub fn get_kind(&self) -> &LevelParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for LevelParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for LevelParseError {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LevelValidationError {
+    EmptyMap,
+    HeightMismatch {
+        expected: i32,
+        found: i32,
+    },
+    RowWidthMismatch {
+        row: i32,
+        expected: i32,
+        found: i32,
+    },
+    NoPlayer,
+    MultiplePlayers(usize),
+    PlayerMismatch {
+        declared: (i32, i32),
+        found: (i32, i32),
+    },
+    NoExit,
+    UnreachableExit,
+    NotEnoughDiamonds {
+        required: i32,
+        available: i32,
+    },
+}
+
+impl fmt::Display for LevelValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LevelValidationError::EmptyMap => write!(f, "the map is empty"),
+            LevelValidationError::HeightMismatch { expected, found } => {
+                write!(f, "expected {} rows, found {}", expected, found)
+            }
+            LevelValidationError::RowWidthMismatch {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} is {} tiles wide, expected {}",
+                row, found, expected
+            ),
+            LevelValidationError::NoPlayer => write!(f, "the map has no player"),
+            LevelValidationError::MultiplePlayers(count) => {
+                write!(f, "the map has {} players, expected one", count)
+            }
+            LevelValidationError::PlayerMismatch { declared, found } => write!(
+                f,
+                "player declared at {:?} but found at {:?}",
+                declared, found
+            ),
+            LevelValidationError::NoExit => write!(f, "the map has no exit"),
+            LevelValidationError::UnreachableExit => {
+                write!(f, "the exit cannot be reached from the player")
+            }
+            LevelValidationError::NotEnoughDiamonds {
+                required,
+                available,
+            } => write!(
+                f,
+                "{} diamonds are required but only {} can be collected",
+                required, available
+            ),
+        }
+    }
+}
+
+impl Error for LevelValidationError {}
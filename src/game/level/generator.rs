@@ -0,0 +1,207 @@
+use crate::game::{enums::movement::Movement, random::Random};
+
+use super::definition::{LevelDefinition, LevelHeader};
+
+const RANDOM_CAVE_WIDTH: i32 = 40;
+const RANDOM_CAVE_HEIGHT: i32 = 22;
+const SOLVABLE_ATTEMPTS: u16 = 1000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CaveObject {
+    Point((i32, i32), char),
+    Line((i32, i32), Movement, i32, char),
+    Rect((i32, i32), (i32, i32), char),
+    FilledRect((i32, i32), (i32, i32), char, char),
+}
+
+#[derive(Clone, Debug)]
+pub struct CaveGenerator {
+    header: LevelHeader,
+    width: i32,
+    height: i32,
+    seed: u8,
+    initial_fill: char,
+    random_fill: Vec<(char, u8)>,
+    objects: Vec<CaveObject>,
+}
+
+impl CaveGenerator {
+    pub fn new(width: i32, height: i32, seed: u8) -> Self {
+        CaveGenerator {
+            header: LevelHeader::default(),
+            width,
+            height,
+            seed,
+            initial_fill: '.',
+            random_fill: Vec::new(),
+            objects: Vec::new(),
+        }
+    }
+
+    pub fn get_header_mut(&mut self) -> &mut LevelHeader {
+        &mut self.header
+    }
+
+    pub fn set_initial_fill(&mut self, tile: char) {
+        self.initial_fill = tile;
+    }
+
+    pub fn add_random_fill(&mut self, tile: char, probability: u8) {
+        self.random_fill.push((tile, probability));
+    }
+
+    pub fn add_object(&mut self, object: CaveObject) {
+        self.objects.push(object);
+    }
+
+    pub fn generate(&self) -> LevelDefinition {
+        let mut map = vec![vec![self.initial_fill; self.width as usize]; self.height as usize];
+
+        let mut random = Random::new(self.seed);
+        for row in map.iter_mut().take(self.height as usize - 1).skip(1) {
+            for tile in row.iter_mut() {
+                let value = random.next_u8();
+                for (fill, probability) in &self.random_fill {
+                    if value < *probability {
+                        *tile = *fill;
+                    }
+                }
+            }
+        }
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if x == 0 || y == 0 || x == self.width - 1 || y == self.height - 1 {
+                    map[y as usize][x as usize] = 'W';
+                }
+            }
+        }
+
+        for object in &self.objects {
+            self.draw_object(&mut map, object);
+        }
+
+        LevelDefinition::new(self.header.clone(), map)
+    }
+
+    fn draw_object(&self, map: &mut [Vec<char>], object: &CaveObject) {
+        let mut put = |(x, y): (i32, i32), tile: char| {
+            if x > 0 && y > 0 && x < self.width - 1 && y < self.height - 1 {
+                map[y as usize][x as usize] = tile;
+            }
+        };
+        match object {
+            CaveObject::Point(position, tile) => put(*position, *tile),
+            CaveObject::Line(start, direction, length, tile) => {
+                let mut position = *start;
+                for _ in 0..*length {
+                    put(position, *tile);
+                    position = direction.edit_position(position);
+                }
+            }
+            CaveObject::Rect((left, top), (width, height), tile) => {
+                for y in *top..top + height {
+                    for x in *left..left + width {
+                        if x == *left || y == *top || x == left + width - 1 || y == top + height - 1
+                        {
+                            put((x, y), *tile);
+                        }
+                    }
+                }
+            }
+            CaveObject::FilledRect((left, top), (width, height), tile, fill) => {
+                for y in *top..top + height {
+                    for x in *left..left + width {
+                        if x == *left || y == *top || x == left + width - 1 || y == top + height - 1
+                        {
+                            put((x, y), *tile);
+                        } else {
+                            put((x, y), *fill);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn random_position(layout: &mut Random) -> (i32, i32) {
+    (
+        1 + (layout.next_u8() as i32 % (RANDOM_CAVE_WIDTH - 2)),
+        1 + (layout.next_u8() as i32 % (RANDOM_CAVE_HEIGHT - 2)),
+    )
+}
+
+pub fn create_random_cave_generator(seed: u16) -> CaveGenerator {
+    let mut layout = Random::new((seed >> 8) as u8 ^ seed as u8);
+    let mut generator = CaveGenerator::new(RANDOM_CAVE_WIDTH, RANDOM_CAVE_HEIGHT, seed as u8);
+
+    let header = generator.get_header_mut();
+    header.set_name(&format!("Random cave {}", seed));
+    header.set_seed(seed as u8);
+    header.set_cave_time(100.0 + (layout.next_u8() % 4) as f64 * 25.0);
+    header.set_diamonds_required(8 + (layout.next_u8() % 12) as i32);
+
+    generator.add_random_fill(' ', 40 + layout.next_u8() % 40);
+    generator.add_random_fill('r', 30 + layout.next_u8() % 40);
+    generator.add_random_fill('d', 6 + layout.next_u8() % 10);
+    if layout.next_u8().is_multiple_of(2) {
+        generator.add_random_fill('q', 2);
+    }
+
+    for _ in 0..2 + layout.next_u8() % 3 {
+        let direction = if layout.next_u8().is_multiple_of(2) {
+            Movement::MoveRight
+        } else {
+            Movement::MoveDown
+        };
+        let length = 4 + (layout.next_u8() % 16) as i32;
+        generator.add_object(CaveObject::Line(
+            random_position(&mut layout),
+            direction,
+            length,
+            'w',
+        ));
+    }
+
+    generator.add_object(CaveObject::Point(random_position(&mut layout), 'X'));
+    generator.add_object(CaveObject::Point(random_position(&mut layout), 'P'));
+    generator
+}
+
+/// Rocks count as walls: only caves whose exit and required diamonds can be reached
+/// without moving a single rock are accepted.
+pub fn is_solvable(level: &LevelDefinition) -> bool {
+    if level.validate().is_err() {
+        return false;
+    }
+    let Some(player) = level.get_player_position() else {
+        return false;
+    };
+    let reachable = level.get_reachable_tiles_avoiding(player, &['r']);
+    if !level
+        .find_chars('X')
+        .iter()
+        .any(|exit| reachable.contains(exit))
+    {
+        return false;
+    }
+    let diamonds = level
+        .find_chars('d')
+        .iter()
+        .filter(|diamond| reachable.contains(diamond))
+        .count() as i32;
+    diamonds >= level.get_header().get_diamonds_required()
+}
+
+pub fn generate_random_cave(seed: u16) -> LevelDefinition {
+    create_random_cave_generator(seed).generate()
+}
+
+/// Returns the first cave passing `is_solvable` among the seeds following `seed`, or
+/// `None` when none of them does.
+pub fn generate_solvable_cave(seed: u16) -> Option<LevelDefinition> {
+    (0..SOLVABLE_ATTEMPTS)
+        .map(|attempt| generate_random_cave(seed.wrapping_add(attempt)))
+        .find(is_solvable)
+}
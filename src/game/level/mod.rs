@@ -0,0 +1,6 @@
+pub mod bdcff;
+pub mod definition;
+pub mod editor;
+pub mod error;
+pub mod generator;
+pub mod validator;
@@ -0,0 +1,125 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::game::enums::{field::Field, movement::Movement};
+
+use super::{definition::LevelDefinition, error::LevelValidationError};
+
+impl LevelDefinition {
+    pub fn validate(&self) -> Result<(), Vec<LevelValidationError>> {
+        let map = self.get_map();
+        if map.is_empty() {
+            return Err(vec![LevelValidationError::EmptyMap]);
+        }
+
+        let mut errors = Vec::new();
+        if map.len() as i32 != self.get_height() {
+            errors.push(LevelValidationError::HeightMismatch {
+                expected: self.get_height(),
+                found: map.len() as i32,
+            });
+        }
+        for (y, row) in map.iter().enumerate() {
+            if row.len() as i32 != self.get_width() {
+                errors.push(LevelValidationError::RowWidthMismatch {
+                    row: y as i32,
+                    expected: self.get_width(),
+                    found: row.len() as i32,
+                });
+            }
+        }
+
+        let players = self.find_chars('P');
+        match players.as_slice() {
+            [] => errors.push(LevelValidationError::NoPlayer),
+            [player] => {
+                if let Some(declared) = self.get_declared_player_position() {
+                    if declared != *player {
+                        errors.push(LevelValidationError::PlayerMismatch {
+                            declared,
+                            found: *player,
+                        });
+                    }
+                }
+            }
+            _ => errors.push(LevelValidationError::MultiplePlayers(players.len())),
+        }
+
+        let reachable = players
+            .first()
+            .map(|player| self.get_reachable_tiles(*player));
+        let count_reachable = |ch: char| {
+            self.find_chars(ch)
+                .iter()
+                .filter(|position| {
+                    reachable
+                        .as_ref()
+                        .is_none_or(|tiles| tiles.contains(position))
+                })
+                .count() as i32
+        };
+
+        let exits = self.find_chars('X');
+        if exits.is_empty() {
+            errors.push(LevelValidationError::NoExit);
+        } else if count_reachable('X') == 0 {
+            errors.push(LevelValidationError::UnreachableExit);
+        }
+
+        let can_create_diamonds =
+            !self.find_chars('a').is_empty() || !self.find_chars('M').is_empty();
+        let available = count_reachable('d') + 9 * count_reachable('B');
+        let required = self.get_header().get_diamonds_required();
+        if !can_create_diamonds && available < required {
+            errors.push(LevelValidationError::NotEnoughDiamonds {
+                required,
+                available,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn is_blocking(&self, x: i32, y: i32) -> bool {
+        match self.get_char(x, y) {
+            Some(ch) => matches!(
+                Field::from_char(ch, x, y),
+                None | Some(Field::Wall(_)) | Some(Field::MagicWall(_)) | Some(Field::Slime(_))
+            ),
+            None => true,
+        }
+    }
+
+    pub fn get_reachable_tiles(&self, start: (i32, i32)) -> HashSet<(i32, i32)> {
+        self.get_reachable_tiles_avoiding(start, &[])
+    }
+
+    pub fn get_reachable_tiles_avoiding(
+        &self,
+        start: (i32, i32),
+        obstacles: &[char],
+    ) -> HashSet<(i32, i32)> {
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(position) = queue.pop_front() {
+            for direction in [
+                Movement::MoveUp,
+                Movement::MoveDown,
+                Movement::MoveLeft,
+                Movement::MoveRight,
+            ] {
+                let (x, y) = direction.edit_position(position);
+                let avoided = self
+                    .get_char(x, y)
+                    .is_some_and(|ch| obstacles.contains(&ch));
+                if !avoided && !self.is_blocking(x, y) && visited.insert((x, y)) {
+                    queue.push_back((x, y));
+                }
+            }
+        }
+        visited
+    }
+}
@@ -0,0 +1,71 @@
+use super::{
+    display::action::Action,
+    enums::{
+        action_type::ActionType, field::Field, magic_wall_state::MagicWallState, movement::Movement,
+    },
+    grid::Grid,
+    interfaces::{collidable::Collidable, renderable::Renderable},
+};
+
+#[derive(Clone, Debug)]
+pub struct MagicWall {
+    position: (i32, i32),
+}
+
+impl MagicWall {
+    pub fn new(x: i32, y: i32) -> Self {
+        MagicWall { position: (x, y) }
+    }
+
+    pub fn is_passable(grid: &Grid) -> bool {
+        grid.get_magic_wall_state() != MagicWallState::Expired
+    }
+
+    pub fn get_exit_position(&self) -> (i32, i32) {
+        Movement::MoveDown.edit_position(self.position)
+    }
+
+    pub fn let_through(&self, grid: &Grid, field: Field) -> Vec<Action> {
+        let (x, y) = self.get_exit_position();
+        let mut actions = vec![Action::new(
+            self.position,
+            Field::MagicWall(self.clone()),
+            ActionType::MagicWallActivate,
+        )];
+        if let Some(tile) = grid.get_tile(x, y) {
+            if tile.get_object_on().is_none() {
+                actions.push(Action::new((x, y), field, ActionType::MagicWallTransform));
+            }
+        }
+        actions
+    }
+
+    pub fn get_frame(&self, current_frame: i32) -> (f64, f64) {
+        let frame_x = if (0..=7).contains(&current_frame) {
+            (current_frame % 4) as f64 + 4.0
+        } else {
+            4.0
+        };
+
+        (frame_x * 32.0, 6.0 * 32.0)
+    }
+}
+
+impl Collidable for MagicWall {
+    fn get_position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    fn get_future_position(&self, _: &Grid) -> (i32, i32) {
+        self.position
+    }
+}
+
+impl Renderable for MagicWall {
+    fn get_sprite_position(&self, grid: &Grid) -> (f64, f64) {
+        match grid.get_magic_wall_state() {
+            MagicWallState::Active(_) => self.get_frame(grid.get_frame()),
+            MagicWallState::Dormant | MagicWallState::Expired => (3.0 * 32.0, 6.0 * 32.0),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use super::interfaces::storage::Storage;
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    items: HashMap<String, String>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        MemoryStorage {
+            items: HashMap::new(),
+        }
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.items.get(key).cloned()
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) {
+        self.items.insert(key.to_string(), value.to_string());
+    }
+
+    fn remove_item(&mut self, key: &str) {
+        self.items.remove(key);
+    }
+}
@@ -2,9 +2,22 @@ pub mod display;
 pub mod enums;
 pub mod interfaces;
 
+pub mod amoeba;
+pub mod bomb;
 pub mod diamond;
+pub mod enemy;
 pub mod grid;
+pub mod input;
+pub mod level;
+pub mod magic_wall;
+pub mod memory_storage;
 pub mod player;
+pub mod random;
+pub mod replay;
 pub mod rock;
+pub mod save;
+pub mod session;
+pub mod slime;
+pub mod snapshot;
 pub mod tile;
 pub mod wall;
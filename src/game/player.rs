@@ -1,12 +1,10 @@
 use std::any::Any;
 use std::rc::Rc;
 
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
-
-use super::diamond::Diamond;
+use super::bomb::{Bomb, BOMB_FUSE};
 use super::display::action::Action;
-use super::display::zone::Zone;
 use super::enums::action_type::ActionType;
+use super::enums::entity_state::EntityState;
 use super::enums::field::Field;
 use super::enums::movement::Movement;
 use super::grid::Grid;
@@ -19,6 +17,7 @@ pub struct Player {
     position: (i32, i32),
     doing: Movement,
     pushing: Option<Movement>,
+    bomb_fuse: Option<i32>,
 }
 
 impl Player {
@@ -27,6 +26,7 @@ impl Player {
             position: (x, y),
             doing: Movement::Afk,
             pushing: None,
+            bomb_fuse: None,
         }
     }
 
@@ -81,7 +81,118 @@ impl Player {
         actions
     }
 
+    pub fn snap(&self, grid: &Grid) -> Vec<Action> {
+        let mut actions = Vec::new();
+        let (tx, ty) = self.doing.edit_position(self.position);
+        if let Some(tile) = grid.get_tile(tx, ty) {
+            match tile.get_object_on() {
+                Some(Field::Dirt) => {
+                    actions.push(Action::new((tx, ty), Field::Empty, ActionType::WalkOnDirt))
+                }
+                Some(Field::Entity(entity)) if entity.get_type().as_str() == "Diamond" => actions
+                    .push(Action::new(
+                        (tx, ty),
+                        Field::Empty,
+                        ActionType::ClaimDiamond,
+                    )),
+                Some(Field::Entity(entity))
+                    if entity
+                        .as_any()
+                        .downcast_ref::<Bomb>()
+                        .is_some_and(|bomb| !bomb.is_lit()) =>
+                {
+                    actions.push(Action::new((tx, ty), Field::Empty, ActionType::CollectBomb))
+                }
+                _ => {}
+            }
+        }
+        let mut self_clone = self.clone();
+        self_clone.doing = Movement::Afk;
+        self_clone.pushing = None;
+        actions.push(Action::new(
+            self.position,
+            Field::Entity(Rc::new(self_clone)),
+            ActionType::PlayerSetMovement,
+        ));
+        actions
+    }
+
+    pub fn drop_bomb(&self, grid: &Grid) -> Vec<Action> {
+        let mut self_clone = self.clone();
+        self_clone.doing = Movement::Afk;
+        self_clone.pushing = None;
+        let action_type = if grid.get_bombs_held() > 0 && self.bomb_fuse.is_none() {
+            self_clone.bomb_fuse = Some(BOMB_FUSE);
+            ActionType::DropBomb
+        } else {
+            ActionType::PlayerSetMovement
+        };
+        vec![Action::new(
+            self.position,
+            Field::Entity(Rc::new(self_clone)),
+            action_type,
+        )]
+    }
+
+    pub fn is_on_lit_bomb(&self) -> bool {
+        self.bomb_fuse.is_some()
+    }
+
+    fn act(&self, grid: &Grid) -> Vec<Action> {
+        if self.doing.is_snap() {
+            return self.snap(grid);
+        }
+        if self.doing == Movement::DropBomb {
+            return self.drop_bomb(grid);
+        }
+
+        let mut actions = Vec::new();
+        let (x, y) = self.position;
+        let (fx, fy) = self.get_future_position(grid);
+
+        if let Some(tile) = grid.get_tile(fx, fy) {
+            match tile.get_object_on() {
+                Some(Field::Entity(entity)) => {
+                    if entity.get_type().as_str() == "Rock" {
+                        let rock = entity
+                            .as_any()
+                            .downcast_ref::<Rock>()
+                            .expect("Downcast failed for a Rock")
+                            .clone();
+                        actions.extend(self.push_rock(grid, &rock));
+                    } else if entity.get_type().as_str() == "Diamond"
+                        || entity
+                            .as_any()
+                            .downcast_ref::<Bomb>()
+                            .is_some_and(|bomb| !bomb.is_lit())
+                    {
+                        actions.extend(self.move_to(grid, x, y, fx, fy));
+                    } else {
+                        actions.push(self.cancel_push(true));
+                    };
+                }
+                Some(Field::Exit) => {
+                    if grid.is_exit_open() {
+                        actions.extend(self.move_to(grid, x, y, fx, fy));
+                    } else {
+                        actions.push(self.cancel_push(false));
+                    }
+                }
+                Some(Field::Wall(_))
+                | Some(Field::Amoeba(_))
+                | Some(Field::MagicWall(_))
+                | Some(Field::Slime(_)) => actions.push(self.cancel_push(false)),
+                _ => actions.extend(self.move_to(grid, x, y, fx, fy)),
+            };
+        };
+        actions
+    }
+
     pub fn get_frame(&self, current_frame: i32, action: Movement) -> (f64, f64) {
+        if action.is_snap() {
+            return (0.0, 0.0);
+        }
+
         let row = match action {
             Movement::MoveLeft => 4.0,
             Movement::MoveRight => 5.0,
@@ -101,15 +212,24 @@ impl Player {
 impl Movable for Player {
     fn move_to(&self, grid: &Grid, ax: i32, ay: i32, nx: i32, ny: i32) -> Vec<Action> {
         let mut actions = Vec::new();
-        actions.push(Action::new(
-            (ax, ay),
-            Field::Empty,
-            ActionType::NoMoreEntityOnTile,
-        ));
+        if let Some(fuse) = self.bomb_fuse {
+            actions.push(Action::new(
+                (ax, ay),
+                Field::Entity(Rc::new(Bomb::lit(ax, ay, fuse))),
+                ActionType::BombTick,
+            ));
+        } else {
+            actions.push(Action::new(
+                (ax, ay),
+                Field::Empty,
+                ActionType::NoMoreEntityOnTile,
+            ));
+        }
         let mut self_clone = self.clone();
         self_clone.doing = Movement::Afk;
         self_clone.position = (nx, ny);
         self_clone.pushing = None;
+        self_clone.bomb_fuse = None;
         if let Some(tile) = grid.get_tile(nx, ny) {
             match tile.get_object_on() {
                 Some(Field::Entity(entity)) => {
@@ -119,6 +239,12 @@ impl Movable for Player {
                             Field::Entity(Rc::new(self_clone)),
                             ActionType::ClaimDiamond,
                         ));
+                    } else if entity.get_type().as_str() == "Bomb" {
+                        actions.push(Action::new(
+                            (nx, ny),
+                            Field::Entity(Rc::new(self_clone)),
+                            ActionType::CollectBomb,
+                        ));
                     } else {
                         actions.push(Action::new(
                             (nx, ny),
@@ -146,6 +272,7 @@ impl Collidable for Player {
     fn get_future_position(&self, grid: &Grid) -> (i32, i32) {
         match self.doing {
             Movement::Afk => self.position,
+            direction if direction.is_snap() => self.position,
             direction => {
                 if let Some(tile) =
                     grid.get_nearest_tile(self.position.0, self.position.1, direction)
@@ -160,7 +287,10 @@ impl Collidable for Player {
                             },
                             _ => return direction.edit_position(self.position),
                         },
-                        Some(Field::Wall(_)) => return self.position,
+                        Some(Field::Wall(_))
+                        | Some(Field::Amoeba(_))
+                        | Some(Field::MagicWall(_))
+                        | Some(Field::Slime(_)) => return self.position,
                         Some(Field::Exit) | Some(Field::Empty) | Some(Field::Dirt) | None => {
                             return direction.edit_position(self.position)
                         }
@@ -173,35 +303,16 @@ impl Collidable for Player {
 }
 
 impl Renderable for Player {
-    fn render(
-        &self,
-        grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
-        let (dx, dy) = zone.get_patched_position(self.position);
-
-        let direction: Movement;
-        if grid.get_last_frame_direction() == Movement::Afk {
-            direction = Movement::Afk;
+    fn get_sprite_position(&self, grid: &Grid) -> (f64, f64) {
+        let direction = if grid.get_last_frame_direction() == Movement::Afk {
+            Movement::Afk
+        } else if grid.get_last_frame_direction().is_snap() {
+            grid.get_last_frame_direction()
         } else {
-            direction = grid.get_last_frame_side_direction();
-        }
+            grid.get_last_frame_side_direction()
+        };
 
-        let (sx, sy) = self.get_frame(grid.get_frame(), direction);
-        let _ = context
-            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
-                &sprites,
-                sx,
-                sy,
-                32.0,
-                32.0,
-                dx,
-                dy + 32.0,
-                32.0,
-                32.0,
-            );
+        self.get_frame(grid.get_frame(), direction)
     }
 }
 
@@ -215,38 +326,49 @@ impl Entity for Player {
     }
 
     fn update(&self, grid: &Grid) -> Vec<Action> {
-        let mut actions = Vec::new();
-        let (x, y) = self.position;
-        let (fx, fy) = self.get_future_position(&grid);
-
-        if let Some(tile) = grid.get_tile(fx, fy) {
-            match tile.get_object_on() {
-                Some(Field::Entity(entity)) => {
-                    if entity.get_type().as_str() == "Rock" {
-                        let rock = entity
-                            .as_any()
-                            .downcast_ref::<Rock>()
-                            .expect("Downcast failed for a Rock")
-                            .clone();
-                        actions.extend(self.push_rock(grid, &rock));
-                    } else if entity.get_type().as_str() == "Diamond" {
-                        actions.extend(self.move_to(grid, x, y, fx, fy));
-                    } else {
-                        actions.push(self.cancel_push(true));
-                    };
-                }
-                Some(Field::Exit) => {
-                    if grid.get_tiles_with_entity::<Diamond>().len() == 0 {
-                        actions.extend(self.move_to(grid, x, y, fx, fy));
-                    };
-                }
-                Some(Field::Wall(_)) => actions.push(self.cancel_push(false)),
-                _ => actions.extend(self.move_to(grid, x, y, fx, fy)),
-            };
+        let Some(fuse) = self.bomb_fuse else {
+            return self.act(grid);
         };
+        if fuse <= 1 {
+            return grid.get_explosion_actions(self.position, ActionType::ExplodeToSpace);
+        }
+        let mut self_clone = self.clone();
+        self_clone.bomb_fuse = Some(fuse - 1);
+        let mut actions = self_clone.act(grid);
+        if actions.is_empty() {
+            actions.push(Action::new(
+                self.position,
+                Field::Entity(Rc::new(self_clone)),
+                ActionType::BombTick,
+            ));
+        }
         actions
     }
 
+    fn get_state(&self) -> Option<EntityState> {
+        Some(EntityState::Player {
+            doing: self.doing,
+            pushing: self.pushing,
+            bomb_fuse: self.bomb_fuse,
+        })
+    }
+
+    fn restore_state(&self, state: EntityState) -> Option<Rc<dyn Entity>> {
+        let EntityState::Player {
+            doing,
+            pushing,
+            bomb_fuse,
+        } = state
+        else {
+            return None;
+        };
+        let mut self_clone = self.clone();
+        self_clone.doing = doing;
+        self_clone.pushing = pushing;
+        self_clone.bomb_fuse = bomb_fuse;
+        Some(Rc::new(self_clone))
+    }
+
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Player at {:?}", self.position)
     }
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Random {
+    seed1: u8,
+    seed2: u8,
+}
+
+impl Random {
+    pub fn new(seed: u8) -> Self {
+        Random {
+            seed1: 0,
+            seed2: seed,
+        }
+    }
+
+    // Port of the pseudo-random generator used by the original game.
+    pub fn next_u8(&mut self) -> u8 {
+        let temp_1 = (self.seed1 as u16 & 0x01) << 7;
+        let temp_2 = (self.seed2 as u16 >> 1) & 0x7f;
+
+        let mut result = self.seed2 as u16 + (self.seed2 as u16 & 0x01) * 0x80;
+        let mut carry = (result > 0xff) as u16;
+        result = (result & 0xff) + carry + 0x13;
+        carry = (result > 0xff) as u16;
+        self.seed2 = (result & 0xff) as u8;
+
+        result = self.seed1 as u16 + carry + temp_1;
+        carry = (result > 0xff) as u16;
+        result = (result & 0xff) + carry + temp_2;
+        self.seed1 = (result & 0xff) as u8;
+
+        self.seed1
+    }
+}
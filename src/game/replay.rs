@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    enums::{event::Event, movement::Movement},
+    grid::Grid,
+    level::{definition::LevelDefinition, error::LevelParseError},
+    snapshot::Snapshot,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Replay {
+    level: u32,
+    difficulty: u32,
+    seed: Option<u16>,
+    cave: String,
+    snapshot: Option<Snapshot>,
+    inputs: Vec<Movement>,
+}
+
+impl Replay {
+    pub fn new(level: u32, difficulty: u32, seed: Option<u16>, cave: &LevelDefinition) -> Self {
+        Replay {
+            level,
+            difficulty,
+            seed,
+            cave: cave.to_level_string(),
+            snapshot: None,
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn from_snapshot(
+        level: u32,
+        difficulty: u32,
+        seed: Option<u16>,
+        snapshot: Snapshot,
+    ) -> Self {
+        Replay {
+            level,
+            difficulty,
+            seed,
+            cave: String::new(),
+            snapshot: Some(snapshot),
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, input: Movement) {
+        self.inputs.push(input);
+    }
+
+    pub fn get_input(&self, tick: usize) -> Option<Movement> {
+        self.inputs.get(tick).copied()
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn get_difficulty(&self) -> u32 {
+        self.difficulty
+    }
+
+    pub fn get_seed(&self) -> Option<u16> {
+        self.seed
+    }
+
+    pub fn has_cave(&self) -> bool {
+        !self.cave.is_empty() || self.snapshot.is_some()
+    }
+
+    pub fn get_cave(&self) -> Result<LevelDefinition, LevelParseError> {
+        match &self.snapshot {
+            Some(snapshot) => snapshot.get_level(),
+            None => self.cave.parse(),
+        }
+    }
+
+    pub fn get_snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+
+    pub fn create_grid(&self) -> Result<Grid, LevelParseError> {
+        match &self.snapshot {
+            Some(snapshot) => Grid::from_snapshot(snapshot),
+            None => Ok(Grid::new(&self.get_cave()?)),
+        }
+    }
+
+    pub fn get_inputs(&self) -> &[Movement] {
+        &self.inputs
+    }
+
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    pub fn play(&self, grid: &mut Grid) -> Vec<Event> {
+        let mut events = Vec::new();
+        for input in &self.inputs {
+            events.extend(grid.step(*input));
+        }
+        events
+    }
+}
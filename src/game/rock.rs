@@ -1,17 +1,18 @@
 use std::{any::Any, rc::Rc};
 
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
-
 use crate::game::tile::Tile;
 
 use super::{
-    display::{action::Action, zone::Zone},
-    enums::{action_type::ActionType, field::Field, movement::Movement},
+    diamond::Diamond,
+    display::action::Action,
+    enemy::Enemy,
+    enums::{action_type::ActionType, entity_state::EntityState, field::Field, movement::Movement},
     grid::Grid,
     interfaces::{
-        collidable::Collidable, entity::Entity, fallable::Fallable, movable::Movable,
-        renderable::Renderable,
+        collidable::Collidable, entity::Entity, explodable::Explodable, fallable::Fallable,
+        movable::Movable, renderable::Renderable,
     },
+    magic_wall::MagicWall,
 };
 
 #[derive(Clone)]
@@ -49,14 +50,25 @@ impl Movable for Rock {
         self_clone.position = (nx, ny);
         if let Some(tile) = grid.get_tile(nx, ny) {
             match tile.get_object_on() {
-                Some(Field::Entity(entity)) => {
-                    if entity.get_type().as_str() == "Player" {
-                        actions.push(Action::new(
-                            (nx, ny),
-                            Field::Entity(Rc::new(self_clone)),
-                            ActionType::KillPlayer,
-                        ));
-                    };
+                Some(Field::Entity(entity)) => match entity.get_type().as_str() {
+                    "Player" => actions
+                        .extend(grid.get_explosion_actions((nx, ny), ActionType::ExplodeToSpace)),
+                    "Firefly" | "Butterfly" => {
+                        if let Some(enemy) = entity.as_any().downcast_ref::<Enemy>() {
+                            actions.extend(enemy.explode(grid));
+                        }
+                    }
+                    _ => {}
+                },
+                Some(Field::MagicWall(magic_wall)) => {
+                    let (ex, ey) = magic_wall.get_exit_position();
+                    actions.extend(
+                        magic_wall.let_through(grid, Field::Entity(Rc::new(Diamond::new(ex, ey)))),
+                    );
+                }
+                Some(Field::Slime(slime)) => {
+                    self_clone.position = slime.get_exit_position();
+                    actions.extend(slime.let_through(grid, Field::Entity(Rc::new(self_clone))));
                 }
                 _ => actions.push(Action::new(
                     (nx, ny),
@@ -84,28 +96,8 @@ impl Collidable for Rock {
 }
 
 impl Renderable for Rock {
-    fn render(
-        &self,
-        _: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
-        let (dx, dy) = zone.get_patched_position(self.position);
-        let rock_x_in_sprite = 0.0;
-        let rock_y_in_sprite = (7 * 32) as f64;
-        let _ = context
-            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
-                &sprites,
-                rock_x_in_sprite,
-                rock_y_in_sprite,
-                32.0,
-                32.0,
-                dx,
-                dy + 32.0,
-                32.0,
-                32.0,
-            );
+    fn get_sprite_position(&self, _: &Grid) -> (f64, f64) {
+        (0.0, (7 * 32) as f64)
     }
 }
 
@@ -124,6 +116,21 @@ impl Entity for Rock {
         actions
     }
 
+    fn get_state(&self) -> Option<EntityState> {
+        Some(EntityState::Falling {
+            falling_since: self.falling_since,
+        })
+    }
+
+    fn restore_state(&self, state: EntityState) -> Option<Rc<dyn Entity>> {
+        let EntityState::Falling { falling_since } = state else {
+            return None;
+        };
+        let mut self_clone = self.clone();
+        self_clone.falling_since = falling_since;
+        Some(Rc::new(self_clone))
+    }
+
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Rock at {:?}", self.position)
     }
@@ -165,18 +172,36 @@ impl Fallable for Rock {
             let tile = tile?;
             match tile.get_object_on() {
                 Some(Field::Entity(entity)) => {
-                    if entity.get_type().as_str() == "Player"
-                        && falling_since > 0
+                    if matches!(
+                        entity.get_type().as_str(),
+                        "Player" | "Firefly" | "Butterfly"
+                    ) && falling_since > 0
                         && movement == Movement::MoveDown
                     {
                         return Some(movement);
                     }
                 }
-                Some(Field::Wall(_)) | Some(Field::Dirt) | Some(Field::Exit) => {
+                Some(Field::MagicWall(_)) => {
+                    if movement == Movement::MoveDown
+                        && falling_since > 0
+                        && MagicWall::is_passable(grid)
+                    {
+                        return Some(movement);
+                    }
+                }
+                Some(Field::Slime(slime)) => {
+                    if movement == Movement::MoveDown && slime.is_permeable(grid) {
+                        return Some(movement);
+                    }
+                }
+                Some(Field::Wall(_))
+                | Some(Field::Dirt)
+                | Some(Field::Exit)
+                | Some(Field::Amoeba(_)) => {
                     return None;
                 }
                 Some(Field::Empty) | None => {
-                    if depth.map_or(true, |d| d > 0)
+                    if depth.is_none_or(|d| d > 0)
                         || can_move_to(
                             grid.get_nearest_tile(
                                 tile.get_position().0,
@@ -210,7 +235,7 @@ impl Fallable for Rock {
                 grid,
                 depth,
             ) {
-                if movement == Movement::MoveDown || self.is_fallable_near(grid) {
+                if movement == Movement::MoveDown || self.is_on_rounded(grid) {
                     return Some(movement);
                 }
             }
@@ -218,21 +243,16 @@ impl Fallable for Rock {
         None
     }
 
-    fn is_fallable_near(&self, grid: &Grid) -> bool {
-        let directions = vec![Movement::MoveDown, Movement::MoveLeft, Movement::MoveRight];
-        for direction in directions {
-            if let Some(tile) = grid.get_nearest_tile(self.position.0, self.position.1, direction) {
-                match tile.get_object_on() {
-                    Some(Field::Entity(entity)) => {
-                        if entity.get_type().as_str() != "Player" {
-                            return true;
-                        }
-                    }
-                    Some(Field::Wall(_)) | Some(Field::Dirt) | Some(Field::Exit)
-                    | Some(Field::Empty) | None => continue,
-                }
+    fn is_on_rounded(&self, grid: &Grid) -> bool {
+        match grid
+            .get_nearest_tile(self.position.0, self.position.1, Movement::MoveDown)
+            .and_then(|tile| tile.get_object_on())
+        {
+            Some(Field::Entity(entity)) => {
+                matches!(entity.get_type().as_str(), "Rock" | "Diamond")
             }
+            Some(Field::Wall(wall)) => wall.is_rounded(),
+            _ => false,
         }
-        false
     }
 }
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    input::InputBindings, interfaces::storage::Storage, session::Session, snapshot::Snapshot,
+};
+
+const SAVE_KEY: &str = "boulderdash.save";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    sound_enabled: bool,
+    difficulty: u32,
+    bindings: InputBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            sound_enabled: true,
+            difficulty: 1,
+            bindings: InputBindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn is_sound_enabled(&self) -> bool {
+        self.sound_enabled
+    }
+
+    pub fn set_sound_enabled(&mut self, sound_enabled: bool) {
+        self.sound_enabled = sound_enabled;
+    }
+
+    pub fn get_difficulty(&self) -> u32 {
+        self.difficulty
+    }
+
+    pub fn set_difficulty(&mut self, difficulty: u32) {
+        self.difficulty = difficulty;
+    }
+
+    pub fn get_bindings(&self) -> &InputBindings {
+        &self.bindings
+    }
+
+    pub fn get_bindings_mut(&mut self) -> &mut InputBindings {
+        &mut self.bindings
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LevelRecord {
+    best_score: i32,
+    best_time: f64,
+}
+
+impl LevelRecord {
+    pub fn get_best_score(&self) -> i32 {
+        self.best_score
+    }
+
+    pub fn get_best_time(&self) -> f64 {
+        self.best_time
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedGame {
+    level: u32,
+    #[serde(default)]
+    difficulty: Option<u32>,
+    session: Session,
+    snapshot: Snapshot,
+}
+
+impl SavedGame {
+    pub fn new(level: u32, difficulty: u32, session: Session, snapshot: Snapshot) -> Self {
+        SavedGame {
+            level,
+            difficulty: Some(difficulty),
+            session,
+            snapshot,
+        }
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn get_difficulty(&self) -> Option<u32> {
+        self.difficulty
+    }
+
+    pub fn get_session(&self) -> &Session {
+        &self.session
+    }
+
+    pub fn get_snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveData {
+    unlocked_levels: u32,
+    records: BTreeMap<u32, LevelRecord>,
+    settings: Settings,
+    saved_game: Option<SavedGame>,
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        SaveData {
+            unlocked_levels: 1,
+            records: BTreeMap::new(),
+            settings: Settings::default(),
+            saved_game: None,
+        }
+    }
+}
+
+pub struct SaveManager {
+    storage: Box<dyn Storage>,
+    data: SaveData,
+}
+
+impl SaveManager {
+    pub fn new(storage: Box<dyn Storage>) -> Self {
+        let data = SaveManager::load(storage.as_ref()).unwrap_or_default();
+        SaveManager { storage, data }
+    }
+
+    fn load(storage: &dyn Storage) -> Option<SaveData> {
+        storage
+            .get_item(SAVE_KEY)
+            .and_then(|text| serde_json::from_str(&text).ok())
+    }
+
+    pub fn reload(&mut self) {
+        if let Some(data) = SaveManager::load(self.storage.as_ref()) {
+            self.data = data;
+        }
+    }
+
+    fn update(&mut self, change: impl FnOnce(&mut SaveData)) {
+        self.reload();
+        change(&mut self.data);
+        self.save();
+    }
+
+    pub fn save(&mut self) {
+        if let Ok(text) = serde_json::to_string(&self.data) {
+            self.storage.set_item(SAVE_KEY, &text);
+        }
+    }
+
+    pub fn get_unlocked_levels(&self) -> u32 {
+        self.data.unlocked_levels
+    }
+
+    pub fn is_unlocked(&self, level: u32) -> bool {
+        level >= 1 && level <= self.data.unlocked_levels
+    }
+
+    pub fn unlock_level(&mut self, level: u32) {
+        self.update(|data| data.unlocked_levels = data.unlocked_levels.max(level));
+    }
+
+    pub fn get_record(&self, level: u32) -> Option<LevelRecord> {
+        self.data.records.get(&level).copied()
+    }
+
+    pub fn record_result(&mut self, level: u32, score: i32, time: f64) {
+        self.update(|data| {
+            let record = data.records.entry(level).or_insert(LevelRecord {
+                best_score: score,
+                best_time: time,
+            });
+            record.best_score = record.best_score.max(score);
+            record.best_time = record.best_time.min(time);
+        });
+    }
+
+    pub fn get_settings(&self) -> &Settings {
+        &self.data.settings
+    }
+
+    pub fn set_settings(&mut self, settings: Settings) {
+        self.update(|data| data.settings = settings);
+    }
+
+    pub fn get_saved_game(&self) -> Option<&SavedGame> {
+        self.data.saved_game.as_ref()
+    }
+
+    pub fn set_saved_game(&mut self, saved_game: SavedGame) {
+        self.update(|data| data.saved_game = Some(saved_game));
+    }
+
+    pub fn take_saved_game(&mut self) -> Option<SavedGame> {
+        let mut saved_game = None;
+        self.update(|data| saved_game = data.saved_game.take());
+        saved_game
+    }
+
+    pub fn clear_saved_game(&mut self) {
+        self.reload();
+        if self.data.saved_game.take().is_some() {
+            self.save();
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use super::grid::Grid;
+
+const STARTING_LIVES: i32 = 3;
+const EXTRA_LIFE_SCORE: i32 = 500;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    lives: i32,
+    score: i32,
+    extra_lives_awarded: i32,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Session {
+            lives: STARTING_LIVES,
+            score: 0,
+            extra_lives_awarded: 0,
+        }
+    }
+
+    pub fn get_total_score(&self, grid: &Grid) -> i32 {
+        self.score + grid.get_score()
+    }
+
+    pub fn award_extra_lives(&mut self, grid: &Grid) -> bool {
+        let earned = self.get_total_score(grid) / EXTRA_LIFE_SCORE;
+        if earned > self.extra_lives_awarded {
+            self.lives += earned - self.extra_lives_awarded;
+            self.extra_lives_awarded = earned;
+            return true;
+        }
+        false
+    }
+
+    pub fn end_attempt(&mut self, grid: &Grid, completed: bool) {
+        self.score += grid.get_score();
+        if !completed && !grid.is_intermission() {
+            self.lives -= 1;
+        }
+    }
+
+    pub fn get_lives(&self) -> i32 {
+        self.lives
+    }
+
+    pub fn get_score(&self) -> i32 {
+        self.score
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.lives <= 0
+    }
+}
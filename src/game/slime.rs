@@ -0,0 +1,59 @@
+use super::{
+    display::action::Action,
+    enums::{action_type::ActionType, field::Field, movement::Movement},
+    grid::Grid,
+    interfaces::{collidable::Collidable, renderable::Renderable},
+};
+
+const SLIME_FRAMES: [i32; 4] = [7, 8, 9, 8];
+
+#[derive(Clone, Debug)]
+pub struct Slime {
+    position: (i32, i32),
+}
+
+impl Slime {
+    pub fn new(x: i32, y: i32) -> Self {
+        Slime { position: (x, y) }
+    }
+
+    pub fn get_exit_position(&self) -> (i32, i32) {
+        Movement::MoveDown.edit_position(self.position)
+    }
+
+    pub fn is_permeable(&self, grid: &Grid) -> bool {
+        let (x, y) = self.get_exit_position();
+        grid.is_slime_open(self.position)
+            && grid
+                .get_tile(x, y)
+                .is_some_and(|tile| tile.get_object_on().is_none())
+    }
+
+    pub fn let_through(&self, grid: &Grid, field: Field) -> Vec<Action> {
+        let (x, y) = self.get_exit_position();
+        let mut actions = vec![];
+        if let Some(tile) = grid.get_tile(x, y) {
+            if tile.get_object_on().is_none() {
+                actions.push(Action::new((x, y), field, ActionType::SlimeLetThrough));
+            }
+        }
+        actions
+    }
+}
+
+impl Collidable for Slime {
+    fn get_position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    fn get_future_position(&self, _: &Grid) -> (i32, i32) {
+        self.position
+    }
+}
+
+impl Renderable for Slime {
+    fn get_sprite_position(&self, grid: &Grid) -> (f64, f64) {
+        let frame = SLIME_FRAMES[(grid.get_frame() / 2) as usize % SLIME_FRAMES.len()];
+        ((frame * 32) as f64, 0.0)
+    }
+}
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    display::animation::Animation,
+    enums::{entity_state::EntityState, field::Field, magic_wall_state::MagicWallState},
+    level::{
+        definition::{LevelDefinition, LevelHeader},
+        error::{LevelParseError, LevelParseErrorKind},
+    },
+    random::Random,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedEntity {
+    position: (i32, i32),
+    state: EntityState,
+}
+
+impl SavedEntity {
+    pub fn new(position: (i32, i32), state: EntityState) -> Self {
+        SavedEntity { position, state }
+    }
+
+    pub fn get_position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    pub fn get_state(&self) -> EntityState {
+        self.state
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    header: LevelHeader,
+    map: Vec<String>,
+    #[serde(default)]
+    entities: Vec<SavedEntity>,
+    #[serde(default)]
+    animations: Vec<Animation>,
+    score: i32,
+    diamonds_claimed: i32,
+    #[serde(default)]
+    bombs_held: i32,
+    frame: i32,
+    magic_wall_state: MagicWallState,
+    random: Random,
+    amoeba_time: Option<f64>,
+    #[serde(default)]
+    elapsed: f64,
+    #[serde(default)]
+    out_of_time: bool,
+}
+
+impl Snapshot {
+    pub fn new(
+        level: LevelDefinition,
+        score: i32,
+        diamonds_claimed: i32,
+        frame: i32,
+        magic_wall_state: MagicWallState,
+        random: Random,
+        amoeba_time: Option<f64>,
+    ) -> Self {
+        Snapshot {
+            header: level.get_header().clone(),
+            map: level
+                .get_map()
+                .iter()
+                .map(|row| row.iter().collect())
+                .collect(),
+            entities: Vec::new(),
+            animations: Vec::new(),
+            score,
+            diamonds_claimed,
+            bombs_held: 0,
+            frame,
+            magic_wall_state,
+            random,
+            amoeba_time,
+            elapsed: 0.0,
+            out_of_time: false,
+        }
+    }
+
+    pub fn get_level(&self) -> Result<LevelDefinition, LevelParseError> {
+        let mut map = Vec::new();
+        for (y, line) in self.map.iter().enumerate() {
+            let mut row = Vec::new();
+            for (x, ch) in line.chars().enumerate() {
+                if Field::from_char(ch, x as i32, y as i32).is_none() {
+                    return Err(LevelParseError::new(
+                        y + 1,
+                        x + 1,
+                        LevelParseErrorKind::UnknownTile(ch),
+                    ));
+                }
+                row.push(ch);
+            }
+            map.push(row);
+        }
+        Ok(LevelDefinition::new(self.header.clone(), map))
+    }
+
+    pub fn get_entities(&self) -> &[SavedEntity] {
+        &self.entities
+    }
+
+    pub fn set_entities(&mut self, entities: Vec<SavedEntity>) {
+        self.entities = entities;
+    }
+
+    pub fn get_animations(&self) -> &[Animation] {
+        &self.animations
+    }
+
+    pub fn set_animations(&mut self, animations: Vec<Animation>) {
+        self.animations = animations;
+    }
+
+    pub fn get_score(&self) -> i32 {
+        self.score
+    }
+
+    pub fn get_diamonds_claimed(&self) -> i32 {
+        self.diamonds_claimed
+    }
+
+    pub fn get_bombs_held(&self) -> i32 {
+        self.bombs_held
+    }
+
+    pub fn set_bombs_held(&mut self, bombs_held: i32) {
+        self.bombs_held = bombs_held;
+    }
+
+    pub fn get_frame(&self) -> i32 {
+        self.frame
+    }
+
+    pub fn get_magic_wall_state(&self) -> MagicWallState {
+        self.magic_wall_state
+    }
+
+    pub fn get_random(&self) -> Random {
+        self.random
+    }
+
+    pub fn get_amoeba_time(&self) -> Option<f64> {
+        self.amoeba_time
+    }
+
+    pub fn get_elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn set_elapsed(&mut self, elapsed: f64) {
+        self.elapsed = elapsed;
+    }
+
+    pub fn is_out_of_time(&self) -> bool {
+        self.out_of_time
+    }
+
+    pub fn set_out_of_time(&mut self, out_of_time: bool) {
+        self.out_of_time = out_of_time;
+    }
+}
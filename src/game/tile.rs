@@ -1,8 +1,6 @@
 use super::{
-    display::action::Action, display::zone::Zone, enums::field::Field, grid::Grid,
-    interfaces::renderable::Renderable,
+    display::action::Action, enums::field::Field, grid::Grid, interfaces::renderable::Renderable,
 };
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
 
 #[derive(Debug)]
 pub struct Tile {
@@ -18,9 +16,8 @@ impl Tile {
 
     pub fn update(&self, grid: &Grid) -> Vec<Action> {
         let mut actions = vec![];
-        match self.field {
-            Field::Entity(ref entity) => actions.extend(entity.update(grid)),
-            _ => (),
+        if let Field::Entity(ref entity) = self.field {
+            actions.extend(entity.update(grid));
         }
         actions
     }
@@ -31,7 +28,13 @@ impl Tile {
 
     pub fn get_object_on(&self) -> Option<&Field> {
         match &self.field {
-            Field::Entity(_) | Field::Wall(_) | Field::Dirt | Field::Exit => Some(&self.field),
+            Field::Entity(_)
+            | Field::Wall(_)
+            | Field::Dirt
+            | Field::Exit
+            | Field::Amoeba(_)
+            | Field::MagicWall(_)
+            | Field::Slime(_) => Some(&self.field),
             Field::Empty => None,
         }
     }
@@ -39,52 +42,25 @@ impl Tile {
     pub fn set_object_on(&mut self, field: Field) {
         self.field = field;
     }
-
-    pub fn render_non_obj(
-        &self,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-        sx: f64,
-        sy: f64,
-    ) {
-        let (dx, dy) = zone.get_patched_position((self.x, self.y));
-        let _ = context
-            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
-                &sprites,
-                sx,
-                sy,
-                32.0,
-                32.0,
-                dx,
-                dy + 32.0,
-                32.0,
-                32.0,
-            );
-    }
 }
 
 impl Renderable for Tile {
-    fn render(
-        &self,
-        grid: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
+    fn get_sprite_position(&self, grid: &Grid) -> (f64, f64) {
         match &self.get_object_on() {
-            Some(Field::Entity(entity)) => entity.render(grid, context, sprites, zone),
-            Some(Field::Wall(wall)) => wall.render(grid, context, sprites, zone),
-            Some(Field::Dirt) => {
-                self.render_non_obj(context, sprites, zone, (1 * 32) as f64, (7 * 32) as f64)
-            }
-
+            Some(Field::Entity(entity)) => entity.get_sprite_position(grid),
+            Some(Field::Wall(wall)) => wall.get_sprite_position(grid),
+            Some(Field::Amoeba(amoeba)) => amoeba.get_sprite_position(grid),
+            Some(Field::MagicWall(magic_wall)) => magic_wall.get_sprite_position(grid),
+            Some(Field::Slime(slime)) => slime.get_sprite_position(grid),
+            Some(Field::Dirt) => (32.0, (7 * 32) as f64),
             Some(Field::Exit) => {
-                self.render_non_obj(context, sprites, zone, (2 * 32) as f64, (6 * 32) as f64)
-            }
-            Some(Field::Empty) | None => {
-                self.render_non_obj(context, sprites, zone, (0 * 32) as f64, (6 * 32) as f64)
+                if grid.is_exit_open() && (grid.get_frame() / 2) % 2 == 0 {
+                    ((2 * 32) as f64, (6 * 32) as f64)
+                } else {
+                    (32.0, (6 * 32) as f64)
+                }
             }
-        };
+            Some(Field::Empty) | None => (0.0, (6 * 32) as f64),
+        }
     }
 }
@@ -1,7 +1,6 @@
-use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
-
 use super::{
-    display::zone::Zone,
+    display::action::Action,
+    enums::{action_type::ActionType, field::Field, wall_material::WallMaterial},
     grid::Grid,
     interfaces::{collidable::Collidable, renderable::Renderable},
 };
@@ -9,11 +8,53 @@ use super::{
 #[derive(Clone, Debug)]
 pub struct Wall {
     position: (i32, i32),
+    material: WallMaterial,
 }
 
 impl Wall {
-    pub fn new(x: i32, y: i32) -> Self {
-        Wall { position: (x, y) }
+    pub fn new(x: i32, y: i32, material: WallMaterial) -> Self {
+        Wall {
+            position: (x, y),
+            material,
+        }
+    }
+
+    pub fn get_wall_actions(grid: &Grid) -> Vec<Action> {
+        let mut actions = Vec::new();
+        for wall in grid.get_walls() {
+            actions.extend(wall.expand(grid));
+        }
+        actions
+    }
+
+    pub fn expand(&self, grid: &Grid) -> Vec<Action> {
+        let mut actions = Vec::new();
+        for direction in self.material.get_expansion_directions() {
+            let (x, y) = direction.edit_position(self.position);
+            if grid
+                .get_tile(x, y)
+                .is_some_and(|tile| tile.get_object_on().is_none())
+            {
+                actions.push(Action::new(
+                    (x, y),
+                    Field::Wall(Wall::new(x, y, self.material)),
+                    ActionType::WallExpand,
+                ));
+            }
+        }
+        actions
+    }
+
+    pub fn get_material(&self) -> WallMaterial {
+        self.material
+    }
+
+    pub fn is_rounded(&self) -> bool {
+        self.material.is_rounded()
+    }
+
+    pub fn is_explodable(&self) -> bool {
+        self.material.is_explodable()
     }
 }
 
@@ -28,27 +69,7 @@ impl Collidable for Wall {
 }
 
 impl Renderable for Wall {
-    fn render(
-        &self,
-        _: &Grid,
-        context: &mut CanvasRenderingContext2d,
-        sprites: &HtmlImageElement,
-        zone: &Zone,
-    ) {
-        let (dx, dy) = zone.get_patched_position(self.position);
-        let wall_x_in_sprite = (1 * 32) as f64;
-        let wall_y_in_sprite = (6 * 32) as f64;
-        let _ = context
-            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
-                &sprites,
-                wall_x_in_sprite,
-                wall_y_in_sprite,
-                32.0,
-                32.0,
-                dx,
-                dy + 32.0,
-                32.0,
-                32.0,
-            );
+    fn get_sprite_position(&self, _: &Grid) -> (f64, f64) {
+        self.material.get_sprite_position()
     }
 }
@@ -1,17 +1,35 @@
-use js_sys;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::{closure::Closure, JsValue};
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{
-    AudioBuffer, AudioContext, CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement,
-    Response,
-};
+use web_sys::{console, CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement, Response};
+pub mod frontend;
 pub mod game;
 pub mod screen_title;
-use game::{enums::movement::Movement, grid::Grid};
+use frontend::{
+    audio::Audio,
+    camera::Camera,
+    gamepad,
+    local_storage::LocalStorage,
+    menu::{Menu, MenuScreen},
+    overlay::Overlay,
+    renderer::Renderer,
+};
+use game::{
+    enums::{event::Event, field::Field, input_action::InputAction, movement::Movement},
+    grid::Grid,
+    input::{InputBindings, InputQueue, TouchInput},
+    interfaces::storage::Storage,
+    level::{bdcff, definition::LevelDefinition, editor::LevelEditor, generator},
+    memory_storage::MemoryStorage,
+    replay::Replay,
+    save::{SaveManager, SavedGame},
+    session::Session,
+};
 use screen_title::ScreenTitle;
 
+const EXIT_FLASH_DURATION: i32 = 3;
+
 #[wasm_bindgen]
 pub struct GameManager {
     game: Option<Game>,
@@ -23,10 +41,14 @@ pub struct TitleScreenManager {
     context: CanvasRenderingContext2d,
     scroll_offset: f64,
     blink_timer: f64,
+    menu: Menu,
+    save: SaveManager,
+    touch: TouchInput,
 }
 
 #[wasm_bindgen]
 impl TitleScreenManager {
+    #[allow(clippy::new_without_default)]
     #[wasm_bindgen(constructor)]
     pub fn new() -> TitleScreenManager {
         panic!("Use TitleScreenManager::create() instead");
@@ -53,21 +75,90 @@ impl TitleScreenManager {
         let mut screen_title = ScreenTitle::new();
         screen_title.load_images().await;
 
+        let save = Game::create_save_manager();
+        let levels = Game::load_level_files(save.get_settings().get_difficulty() as usize)
+            .await
+            .unwrap_or_default();
+
         TitleScreenManager {
             screen_title,
             context,
             scroll_offset: 0.0,
             blink_timer: 0.0,
+            menu: Menu::new(&levels, &save),
+            save,
+            touch: TouchInput::new(),
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn key_down(&mut self, key: String) -> Result<JsValue, JsValue> {
+        let choice = self.menu.key_down(&key);
+        if self.menu.get_settings() != self.save.get_settings() {
+            self.save.set_settings(self.menu.get_settings().clone());
         }
+        match choice {
+            Some(choice) => Ok(serde_wasm_bindgen::to_value(&choice)?),
+            None => Ok(JsValue::NULL),
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn touch_start(&mut self, x: f64, y: f64, touches: u32) {
+        self.touch.start(x, y, touches);
+    }
+
+    #[wasm_bindgen]
+    pub fn touch_move(&mut self, x: f64, y: f64) {
+        self.touch.move_to(x, y);
+    }
+
+    #[wasm_bindgen]
+    pub fn touch_end(&mut self, touches: u32) -> Result<JsValue, JsValue> {
+        let key = match self.touch.end(touches) {
+            None => return Ok(JsValue::NULL),
+            Some(Movement::MoveUp) => "ArrowUp",
+            Some(Movement::MoveDown) => "ArrowDown",
+            Some(Movement::MoveLeft) => "ArrowLeft",
+            Some(Movement::MoveRight) => "ArrowRight",
+            Some(_) => "Enter",
+        };
+        self.key_down(key.to_string())
+    }
+
+    #[wasm_bindgen]
+    pub fn get_difficulty(&self) -> u32 {
+        self.menu.get_settings().get_difficulty()
+    }
+
+    #[wasm_bindgen]
+    pub async fn return_to_menu(&mut self) {
+        self.save = Game::create_save_manager();
+        let levels = Game::load_level_files(self.get_difficulty() as usize)
+            .await
+            .unwrap_or_default();
+        self.menu = Menu::new(&levels, &self.save);
+        self.menu.show(MenuScreen::Main);
     }
 
     #[wasm_bindgen]
     pub fn update(&mut self) {
         self.scroll_offset += 1.0;
         self.blink_timer += 1.0;
-        
+
+        if self.menu.get_screen() != MenuScreen::Title {
+            self.screen_title.render_menu(
+                &mut self.context,
+                self.scroll_offset,
+                self.menu.get_title(),
+                &self.menu.get_labels(),
+                self.menu.get_selected(),
+            );
+            return;
+        }
+
         let show_text = (self.blink_timer / 30.0) % 2.0 < 1.0;
-        
+
         self.screen_title
             .render_with_scroll(&mut self.context, self.scroll_offset, show_text);
     }
@@ -81,6 +172,12 @@ impl TitleScreenManager {
     }
 }
 
+impl Default for GameManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[wasm_bindgen]
 impl GameManager {
     #[wasm_bindgen(constructor)]
@@ -89,14 +186,68 @@ impl GameManager {
     }
 
     #[wasm_bindgen]
-    pub async fn start(&mut self) {
-        self.game = Some(Game::new().await);
+    pub async fn start(&mut self, level: u32, difficulty: u32) {
+        self.game = Some(Game::new(level, difficulty as usize).await);
+    }
+
+    #[wasm_bindgen]
+    pub async fn resume(&mut self, difficulty: u32) {
+        let difficulty = Game::create_save_manager()
+            .get_saved_game()
+            .and_then(|saved_game| saved_game.get_difficulty())
+            .unwrap_or(difficulty);
+        let mut game = Game::new(1, difficulty as usize).await;
+        game.resume();
+        self.game = Some(game);
+    }
+
+    #[wasm_bindgen]
+    pub async fn start_with_levels(&mut self, text: String) -> Result<u32, JsValue> {
+        let mut game = Game::new(1, 1).await;
+        let count = game.import_levels(&text, 1)?;
+        self.game = Some(game);
+        Ok(count)
     }
 
     #[wasm_bindgen]
     pub fn key_down(&mut self, key: String) {
         if let Some(game) = &mut self.game {
-            game.key_down(key);
+            game.key_down(&key);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn key_up(&mut self, key: String) {
+        if let Some(game) = &mut self.game {
+            game.key_up(&key);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn release_keys(&mut self) {
+        if let Some(game) = &mut self.game {
+            game.release_keys();
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn touch_start(&mut self, x: f64, y: f64, touches: u32) {
+        if let Some(game) = &mut self.game {
+            game.touch_start(x, y, touches);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn touch_move(&mut self, x: f64, y: f64) {
+        if let Some(game) = &mut self.game {
+            game.touch_move(x, y);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn touch_end(&mut self, touches: u32) {
+        if let Some(game) = &mut self.game {
+            game.touch_end(touches);
         }
     }
 
@@ -106,20 +257,318 @@ impl GameManager {
             game.update();
         }
     }
+
+    #[wasm_bindgen]
+    pub fn is_finished(&self) -> bool {
+        self.game.as_ref().is_some_and(|game| game.is_finished())
+    }
+
+    #[wasm_bindgen]
+    pub fn import_levels(&mut self, text: &str, difficulty: u32) -> Result<u32, JsValue> {
+        let game = self.game.as_mut().ok_or("Game is not started")?;
+        game.import_levels(text, difficulty as usize)
+    }
+
+    #[wasm_bindgen]
+    pub fn play_random_cave(&mut self, seed: u16) -> Result<(), JsValue> {
+        let game = self.game.as_mut().ok_or("Game is not started")?;
+        game.play_random_cave(seed);
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub fn save_progress(&mut self) {
+        if let Some(game) = &mut self.game {
+            game.save_progress();
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn export_level(&self) -> Result<String, JsValue> {
+        let game = self.game.as_ref().ok_or("Game is not started")?;
+        Ok(game.get_grid().to_level_string())
+    }
+
+    #[wasm_bindgen]
+    pub fn export_snapshot(&self) -> Result<JsValue, JsValue> {
+        let game = self.game.as_ref().ok_or("Game is not started")?;
+        Ok(serde_wasm_bindgen::to_value(
+            &game.get_grid().to_snapshot(),
+        )?)
+    }
+
+    #[wasm_bindgen]
+    pub fn export_replay(&self) -> Result<JsValue, JsValue> {
+        let game = self.game.as_ref().ok_or("Game is not started")?;
+        Ok(serde_wasm_bindgen::to_value(game.get_replay())?)
+    }
+
+    #[wasm_bindgen]
+    pub fn load_replay(&mut self, replay: JsValue) -> Result<(), JsValue> {
+        let game = self.game.as_mut().ok_or("Game is not started")?;
+        let replay: Replay = serde_wasm_bindgen::from_value(replay)?;
+        game.load_replay(replay)
+    }
+}
+
+#[wasm_bindgen]
+pub struct LevelEditorManager {
+    editor: LevelEditor,
+    renderer: Renderer,
+    overlay: Overlay,
+    brush: char,
+    painting: bool,
+}
+
+#[wasm_bindgen]
+impl LevelEditorManager {
+    #[allow(clippy::new_without_default)]
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> LevelEditorManager {
+        panic!("Use LevelEditorManager::create() instead");
+    }
+
+    #[wasm_bindgen]
+    pub async fn create(width: i32, height: i32) -> LevelEditorManager {
+        let editor = LevelEditorManager {
+            editor: LevelEditor::new(width.max(3), height.max(3)),
+            renderer: Game::create_renderer().await,
+            overlay: Overlay::new(),
+            brush: 'W',
+            painting: false,
+        };
+        editor.render();
+        editor
+    }
+
+    #[wasm_bindgen]
+    pub fn load(&mut self, text: &str) -> Result<(), JsValue> {
+        let level = if bdcff::is_bdcff(text) {
+            bdcff::parse_bdcff(text, 1)
+                .map_err(|error| error.to_string())?
+                .into_iter()
+                .next()
+                .ok_or("No cave found")?
+        } else {
+            text.parse::<LevelDefinition>()
+                .map_err(|error| error.to_string())?
+        };
+        self.editor = LevelEditor::from_level(level);
+        self.render();
+        Ok(())
+    }
+
+    #[wasm_bindgen]
+    pub fn set_brush(&mut self, tile: char) -> bool {
+        if Field::from_char(tile, 0, 0).is_none() {
+            return false;
+        }
+        self.brush = tile;
+        true
+    }
+
+    #[wasm_bindgen]
+    pub fn pointer_down(&mut self, x: f64, y: f64) {
+        self.painting = true;
+        self.editor.begin_stroke();
+        self.pointer_move(x, y);
+    }
+
+    #[wasm_bindgen]
+    pub fn pointer_move(&mut self, x: f64, y: f64) {
+        if !self.painting {
+            return;
+        }
+        let tile_size = self.get_tile_size();
+        let (x, y) = (
+            (x / tile_size).floor() as i32,
+            ((y - 32.0) / tile_size).floor() as i32,
+        );
+        if self.editor.paint(x, y, self.brush) {
+            self.render();
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn pointer_up(&mut self) {
+        self.painting = false;
+        self.editor.end_stroke();
+    }
+
+    #[wasm_bindgen]
+    pub fn get_cave_time(&self) -> f64 {
+        self.editor.get_level().get_header().get_cave_time()
+    }
+
+    #[wasm_bindgen]
+    pub fn set_cave_time(&mut self, cave_time: f64) {
+        if self.editor.set_cave_time(cave_time) {
+            self.render();
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn get_diamonds_required(&self) -> i32 {
+        self.editor.get_level().get_header().get_diamonds_required()
+    }
+
+    #[wasm_bindgen]
+    pub fn set_diamonds_required(&mut self, diamonds_required: i32) {
+        if self.editor.set_diamonds_required(diamonds_required) {
+            self.render();
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn undo(&mut self) -> bool {
+        let changed = self.editor.undo();
+        self.render();
+        changed
+    }
+
+    #[wasm_bindgen]
+    pub fn redo(&mut self) -> bool {
+        let changed = self.editor.redo();
+        self.render();
+        changed
+    }
+
+    #[wasm_bindgen]
+    pub fn validate(&self) -> Vec<String> {
+        match self.editor.get_level().validate() {
+            Ok(()) => vec![],
+            Err(errors) => errors.iter().map(|error| error.to_string()).collect(),
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn export(&self) -> String {
+        self.editor.export()
+    }
+
+    #[wasm_bindgen]
+    pub fn render(&self) {
+        let context = self.renderer.get_context();
+        let canvas = context.canvas().expect("No canvas found");
+        context.set_fill_style_str("black");
+        context.fill_rect(0.0, 0.0, canvas.width() as f64, canvas.height() as f64);
+
+        let mut level = self.editor.get_level().clone();
+        level.get_header_mut().set_diamonds_required(0);
+        let grid = Grid::new(&level);
+        self.renderer.render_level(&grid, self.get_tile_size());
+
+        let header = format!(
+            "TIME {:03}  DIAMONDS {:02}",
+            self.get_cave_time() as i32,
+            self.get_diamonds_required()
+        );
+        self.overlay.render_sentence(
+            context,
+            self.renderer.get_sprites(),
+            &header,
+            true,
+            (0.0, 0.0),
+        );
+    }
+
+    fn get_tile_size(&self) -> f64 {
+        let (canvas_width, canvas_height) = self.renderer.get_canvas_size();
+        let level = self.editor.get_level();
+        (canvas_width as f64 / level.get_width() as f64)
+            .min(canvas_height as f64 / level.get_height() as f64)
+            .min(32.0)
+    }
 }
 
 pub struct Game {
     grid: Grid,
-    context: CanvasRenderingContext2d,
-    audio_context: AudioContext,
-    sprites: HtmlImageElement,
-    sounds: Vec<AudioBuffer>,
-    levels: Vec<String>,
+    renderer: Renderer,
+    audio: Audio,
+    camera: Camera,
+    overlay: Overlay,
+    levels: Vec<LevelDefinition>,
     current_level: u32,
+    difficulty: usize,
+    input: InputQueue,
+    keys_down: Vec<String>,
+    gamepad_buttons: Vec<&'static str>,
+    touch: TouchInput,
+    touch_direction: Movement,
+    touch_snap: bool,
+    paused: bool,
+    quit: bool,
+    replay: Replay,
+    playback: Option<Replay>,
+    session: Session,
+    game_over_timer: Option<i32>,
+    flash_timer: i32,
+    random_seed: Option<u16>,
+    save: SaveManager,
+    save_enabled: bool,
 }
 
 impl Game {
-    pub async fn new() -> Self {
+    pub async fn new(level: u32, difficulty: usize) -> Self {
+        let levels = Game::load_level_files(difficulty)
+            .await
+            .expect("Failed to load level files");
+        let audio = Audio::new().await.expect("Failed to load sound files");
+        let renderer = Game::create_renderer().await;
+        let save = Game::create_save_manager();
+        let current_level = if Game::get_level(level, &levels).is_some() {
+            level
+        } else {
+            1
+        };
+        let level = Game::get_level(current_level, &levels).expect("There is no level 1");
+        let grid = Grid::new(level);
+        let replay = Replay::new(
+            current_level,
+            difficulty as u32,
+            None,
+            &grid.to_level_definition(),
+        );
+        let camera = Game::create_camera(&grid, &renderer);
+        renderer.render_zone(&grid, camera.get_zone());
+
+        Game {
+            grid,
+            renderer,
+            audio,
+            camera,
+            overlay: Overlay::new(),
+            levels,
+            current_level,
+            difficulty,
+            input: InputQueue::new(),
+            keys_down: Vec::new(),
+            gamepad_buttons: Vec::new(),
+            touch: TouchInput::new(),
+            touch_direction: Movement::Afk,
+            touch_snap: false,
+            paused: false,
+            quit: false,
+            replay,
+            playback: None,
+            session: Session::new(),
+            game_over_timer: None,
+            flash_timer: 0,
+            random_seed: None,
+            save,
+            save_enabled: true,
+        }
+    }
+
+    pub(crate) fn create_save_manager() -> SaveManager {
+        let storage: Box<dyn Storage> = match LocalStorage::new() {
+            Some(storage) => Box::new(storage),
+            None => Box::new(MemoryStorage::new()),
+        };
+        SaveManager::new(storage)
+    }
+
+    pub(crate) async fn create_renderer() -> Renderer {
         let window = web_sys::window().expect("No global `window` exists");
         let document = window.document().expect("Should have a document on window");
         let canvas = document
@@ -129,15 +578,7 @@ impl Game {
             .dyn_into::<HtmlCanvasElement>()
             .expect("Element should be a canvas");
 
-        let levels = Game::load_level_files()
-            .await
-            .expect("Failed to load level files");
-        let audio_context = AudioContext::new().expect("Failed to create audio context");
-        let sounds = Game::load_sound_files(&audio_context)
-            .await
-            .expect("Failed to load sound files");
-
-        let mut context = canvas
+        let context = canvas
             .get_context("2d")
             .expect("Failed to get 2d context")
             .expect("Should have a 2d context on canvas")
@@ -158,75 +599,103 @@ impl Game {
 
         image_loaded.await.expect("Failed to load image");
 
-        let level_text = Game::get_level_text(1, &levels).expect("There is no level 1");
-        let canvas_width = context.canvas().expect("No canvas found").width();
-        let canvas_height = context.canvas().expect("No canvas found").height() - 32;
-        let mut grid = Grid::new(&level_text, canvas_width as i32, canvas_height as i32);
-
-        grid.render_player_zone(&mut context, &sprites);
+        Renderer::new(context, sprites)
+    }
 
-        Game {
-            grid,
-            context,
-            audio_context,
-            sprites,
-            sounds,
-            levels,
-            current_level: 1,
-        }
+    pub fn get_level(level: u32, levels: &[LevelDefinition]) -> Option<&LevelDefinition> {
+        levels.get((level as usize).checked_sub(1)?)
     }
 
-    pub fn get_level_text(level: u32, levels: &Vec<String>) -> Option<&String> {
-        levels.get(level as usize - 1)
+    fn create_camera(grid: &Grid, renderer: &Renderer) -> Camera {
+        let (canvas_width, canvas_height) = renderer.get_canvas_size();
+        Camera::new(
+            grid.get_width(),
+            grid.get_height(),
+            canvas_width,
+            canvas_height,
+            grid.get_player_position(),
+        )
     }
 
-    async fn load_level_files() -> Result<Vec<String>, JsValue> {
-        let mut level_files = Vec::new();
+    async fn load_level_files(difficulty: usize) -> Result<Vec<LevelDefinition>, JsValue> {
+        let mut levels = Vec::new();
         let mut i = 1;
         loop {
             let path = format!("./static/maps/level_{}.bbcff", i);
             match Game::load_text_file(&path).await {
-                Ok(text) => level_files.push(text),
+                Ok(text) => levels.extend(Game::parse_levels(&path, &text, difficulty)),
                 Err(_) => break,
             }
             i += 1;
         }
-        Ok(level_files)
+        Ok(levels)
     }
 
-    async fn load_text_file(path: &str) -> Result<String, JsValue> {
-        let resp = Game::load_file(path).await?;
-        let text = JsFuture::from(resp.text()?).await?;
-        Ok(text.as_string().unwrap())
+    fn parse_levels(path: &str, text: &str, difficulty: usize) -> Vec<LevelDefinition> {
+        let parsed = if bdcff::is_bdcff(text) {
+            bdcff::parse_bdcff(text, difficulty)
+        } else {
+            text.parse().map(|level| vec![level])
+        };
+        let levels = match parsed {
+            Ok(levels) => levels,
+            Err(error) => {
+                console::error_1(&format!("{}: {}", path, error).into());
+                return vec![];
+            }
+        };
+        levels
+            .into_iter()
+            .filter(|level| match level.validate() {
+                Ok(()) => true,
+                Err(errors) => {
+                    for error in errors {
+                        console::error_1(
+                            &format!("{} ({}): {}", path, level.get_header().get_name(), error)
+                                .into(),
+                        );
+                    }
+                    false
+                }
+            })
+            .collect()
     }
 
-    async fn load_sound_files(audio_context: &AudioContext) -> Result<Vec<AudioBuffer>, JsValue> {
-        let file_names = vec![
-            "../static/sound/ClaimDiamond.mp3",
-            "../static/sound/DiamondFallOnSomething.mp3",
-            "../static/sound/PlayerMove.mp3",
-            "../static/sound/RockFallOnSomethingOrPushed.mp3",
-            "../static/sound/WalkOnDirt.mp3",
-        ];
-        let mut buffers = Vec::new();
-        for file_name in file_names {
-            buffers.push(Game::load_sound_file(file_name, audio_context).await?);
+    pub fn import_levels(&mut self, text: &str, difficulty: usize) -> Result<u32, JsValue> {
+        let levels = Game::parse_levels("import", text, difficulty);
+        if levels.is_empty() {
+            return Err(JsValue::from_str("No playable cave found"));
         }
-        Ok(buffers)
+        self.random_seed = None;
+        self.difficulty = difficulty;
+        self.set_levels(levels);
+        Ok(self.levels.len() as u32)
     }
 
-    async fn load_sound_file(
-        path: &str,
-        audio_context: &AudioContext,
-    ) -> Result<AudioBuffer, JsValue> {
+    pub fn play_random_cave(&mut self, seed: u16) {
+        let Some(level) = generator::generate_solvable_cave(seed) else {
+            console::error_1(&format!("No solvable random cave found from seed {}", seed).into());
+            self.quit = true;
+            return;
+        };
+        self.random_seed = Some(seed);
+        self.set_levels(vec![level]);
+    }
+
+    fn set_levels(&mut self, levels: Vec<LevelDefinition>) {
+        self.save_enabled = false;
+        self.levels = levels;
+        self.current_level = 1;
+        self.next_level(false);
+    }
+
+    async fn load_text_file(path: &str) -> Result<String, JsValue> {
         let resp = Game::load_file(path).await?;
-        let buffer = JsFuture::from(resp.array_buffer()?).await?;
-        let promise = audio_context.decode_audio_data(&buffer.into())?;
-        let audio_buffer = JsFuture::from(promise).await?;
-        Ok(audio_buffer.unchecked_into::<AudioBuffer>())
+        let text = JsFuture::from(resp.text()?).await?;
+        Ok(text.as_string().unwrap())
     }
 
-    async fn load_file(path: &str) -> Result<Response, JsValue> {
+    pub(crate) async fn load_file(path: &str) -> Result<Response, JsValue> {
         let window = web_sys::window().expect("No global `window` exists");
         let resp_value = JsFuture::from(window.fetch_with_str(path)).await?;
         let resp: Response = resp_value.dyn_into().expect("Not a valid Response");
@@ -239,42 +708,370 @@ impl Game {
     }
 
     fn next_level(&mut self, increase_level: bool) {
+        if let (true, Some(seed)) = (increase_level, self.random_seed) {
+            self.play_random_cave(seed.wrapping_add(1));
+            return;
+        }
         if increase_level {
             self.current_level += 1;
         }
-        if let Some(level_text) = Game::get_level_text(self.current_level, &self.levels) {
-            let canvas_width = self.context.canvas().expect("No canvas found").width();
-            let canvas_height = self.context.canvas().expect("No canvas found").height() - 32;
-            self.grid = Grid::new(&level_text, canvas_width as i32, canvas_height as i32);
-            self.grid
-                .render_player_zone(&mut self.context, &self.sprites);
+        if let Some(level) = Game::get_level(self.current_level, &self.levels) {
+            self.set_grid(Grid::new(level));
         } else {
             self.current_level = 1;
             self.next_level(false);
         }
     }
 
-    pub fn key_down(&mut self, key: String) {
-        match key.as_str() {
-            "ArrowUp" => self.grid.set_player_doing(Movement::MoveUp),
-            "ArrowDown" => self.grid.set_player_doing(Movement::MoveDown),
-            "ArrowLeft" => self.grid.set_player_doing(Movement::MoveLeft),
-            "ArrowRight" => self.grid.set_player_doing(Movement::MoveRight),
+    fn set_grid(&mut self, grid: Grid) {
+        self.playback = None;
+        self.flash_timer = 0;
+        self.grid = grid;
+        self.replay = Replay::new(
+            self.current_level,
+            self.difficulty as u32,
+            self.random_seed,
+            &self.grid.to_level_definition(),
+        );
+        self.camera = Game::create_camera(&self.grid, &self.renderer);
+        self.renderer
+            .render_zone(&self.grid, self.camera.get_zone());
+    }
+
+    pub fn resume(&mut self) {
+        let Some(saved_game) = self.save.take_saved_game() else {
+            return self.next_level(false);
+        };
+        match Grid::from_snapshot(saved_game.get_snapshot()) {
+            Ok(grid) => {
+                self.current_level = saved_game.get_level();
+                self.session = saved_game.get_session().clone();
+                self.set_grid(grid);
+                self.replay = Replay::from_snapshot(
+                    self.current_level,
+                    self.difficulty as u32,
+                    self.random_seed,
+                    saved_game.get_snapshot().clone(),
+                );
+            }
+            Err(error) => {
+                console::error_1(&format!("Could not resume saved game: {}", error).into());
+                self.next_level(false);
+            }
+        }
+    }
+
+    pub fn save_progress(&mut self) {
+        if !self.save_enabled
+            || self.game_over_timer.is_some()
+            || self.grid.is_game_over()
+            || self.grid.is_level_completed()
+        {
+            return;
+        }
+        self.save.set_saved_game(SavedGame::new(
+            self.current_level,
+            self.difficulty as u32,
+            self.session.clone(),
+            self.grid.to_snapshot(),
+        ));
+    }
+
+    pub fn key_down(&mut self, key: &str) {
+        let key = InputBindings::normalize(key);
+        if self.keys_down.contains(&key) {
+            return;
+        }
+        self.keys_down.push(key.clone());
+        if let Some(action) = self.save.get_settings().get_bindings().get_action(&key) {
+            self.press(action);
+        }
+    }
+
+    pub fn key_up(&mut self, key: &str) {
+        let key = InputBindings::normalize(key);
+        let Some(index) = self.keys_down.iter().position(|down| *down == key) else {
+            return;
+        };
+        self.keys_down.remove(index);
+        if let Some(action) = self.save.get_settings().get_bindings().get_action(&key) {
+            self.release(action);
+        }
+    }
+
+    pub fn release_keys(&mut self) {
+        for key in self.keys_down.clone() {
+            self.key_up(&key);
+        }
+    }
+
+    pub fn touch_start(&mut self, x: f64, y: f64, touches: u32) {
+        self.touch.start(x, y, touches);
+        self.sync_touch();
+    }
+
+    pub fn touch_move(&mut self, x: f64, y: f64) {
+        self.touch.move_to(x, y);
+        self.sync_touch();
+    }
+
+    pub fn touch_end(&mut self, touches: u32) {
+        self.touch.end(touches);
+        self.sync_touch();
+    }
+
+    fn sync_touch(&mut self) {
+        let direction = self.touch.get_movement();
+        if direction != self.touch_direction {
+            if self.touch_direction != Movement::Afk {
+                self.release(InputAction::Move(self.touch_direction));
+            }
+            if direction != Movement::Afk {
+                self.press(InputAction::Move(direction));
+            }
+            self.touch_direction = direction;
+        }
+        let snap = self.touch.is_snapping();
+        if snap != self.touch_snap {
+            if snap {
+                self.press(InputAction::Snap);
+            } else {
+                self.release(InputAction::Snap);
+            }
+            self.touch_snap = snap;
+        }
+    }
+
+    fn poll_gamepad(&mut self) {
+        let buttons = gamepad::get_pressed_buttons();
+        for button in std::mem::take(&mut self.gamepad_buttons) {
+            if !buttons.contains(&button) {
+                self.key_up(button);
+            }
+        }
+        for button in &buttons {
+            self.key_down(button);
+        }
+        self.gamepad_buttons = buttons;
+    }
+
+    fn press(&mut self, action: InputAction) {
+        if self.game_over_timer.is_some() || self.quit {
+            return;
+        }
+        match action {
+            InputAction::Move(direction) => {
+                if !self.paused {
+                    self.input.press(direction);
+                }
+            }
+            InputAction::Snap => self.input.press_snap(),
+            InputAction::Bomb => {
+                if !self.paused {
+                    self.input.press_bomb();
+                }
+            }
+            InputAction::Pause => {
+                self.paused = !self.paused;
+                self.input.clear();
+            }
+            InputAction::Restart => {
+                if !self.grid.is_game_over() && !self.grid.is_level_completed() {
+                    self.paused = false;
+                    self.end_attempt(false);
+                }
+            }
+            InputAction::Quit => {
+                self.save_progress();
+                self.quit = true;
+            }
+        }
+    }
+
+    fn release(&mut self, action: InputAction) {
+        match action {
+            InputAction::Move(direction) => self.input.release(direction),
+            InputAction::Snap => self.input.release_snap(),
             _ => {}
         }
     }
 
-    pub fn update(&mut self) {
-        self.grid.update(
-            &mut self.context,
-            &mut self.audio_context,
-            &self.sprites,
-            &self.sounds,
-        );
-        if self.grid.is_level_completed() {
-            self.next_level(true);
-        } else if self.grid.is_game_over() {
+    pub fn get_grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn get_replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    pub fn load_replay(&mut self, replay: Replay) -> Result<(), JsValue> {
+        if !replay.has_cave() {
+            if Game::get_level(replay.get_level(), &self.levels).is_none() {
+                return Err(JsValue::from_str(&format!(
+                    "Replay level {} does not exist",
+                    replay.get_level()
+                )));
+            }
+            self.current_level = replay.get_level();
+            self.next_level(false);
+            self.playback = Some(replay);
+            return Ok(());
+        }
+        if let Some(snapshot) = replay.get_snapshot() {
+            let grid = replay
+                .create_grid()
+                .map_err(|error| JsValue::from_str(&format!("Invalid replay cave: {}", error)))?;
+            self.random_seed = replay.get_seed();
+            self.difficulty = replay.get_difficulty() as usize;
+            self.current_level = replay.get_level();
+            self.set_grid(grid);
+            self.replay = Replay::from_snapshot(
+                replay.get_level(),
+                replay.get_difficulty(),
+                replay.get_seed(),
+                snapshot.clone(),
+            );
+            self.playback = Some(replay);
+            return Ok(());
+        }
+        let cave = replay
+            .get_cave()
+            .map_err(|error| JsValue::from_str(&format!("Invalid replay cave: {}", error)))?;
+        let built_in = replay.get_seed().is_none()
+            && replay.get_difficulty() as usize == self.difficulty
+            && Game::get_level(replay.get_level(), &self.levels)
+                .is_some_and(|level| Grid::new(level).to_level_string() == cave.to_level_string());
+        if built_in {
+            self.current_level = replay.get_level();
             self.next_level(false);
+        } else {
+            self.random_seed = replay.get_seed();
+            self.difficulty = replay.get_difficulty() as usize;
+            self.set_levels(vec![cave]);
+        }
+        self.playback = Some(replay);
+        Ok(())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.quit || self.game_over_timer == Some(0)
+    }
+
+    fn render_game_over(&mut self) {
+        if let Some(timer) = self.game_over_timer {
+            self.overlay.render_game_over(
+                &self.session,
+                self.renderer.get_context(),
+                self.renderer.get_sprites(),
+            );
+            self.game_over_timer = Some((timer - 1).max(0));
+        }
+    }
+
+    fn end_attempt(&mut self, completed: bool) {
+        if self.save_enabled {
+            self.save.clear_saved_game();
+            if completed {
+                self.save.record_result(
+                    self.current_level,
+                    self.grid.get_score(),
+                    self.grid.get_elapsed_time(),
+                );
+                self.save
+                    .unlock_level((self.current_level + 1).min(self.levels.len() as u32));
+            }
+        }
+        self.session.end_attempt(&self.grid, completed);
+        if self.session.is_game_over() {
+            self.game_over_timer = Some(100);
+        } else {
+            self.next_level(completed || self.grid.is_intermission());
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.poll_gamepad();
+        if self.game_over_timer.is_some() {
+            self.render_game_over();
+            return;
+        }
+
+        if self.paused {
+            self.overlay
+                .render_paused(self.renderer.get_context(), self.renderer.get_sprites());
+            return;
+        }
+
+        if self
+            .playback
+            .as_ref()
+            .is_some_and(|playback| self.replay.len() >= playback.len())
+        {
+            self.playback = None;
+        }
+        let input = match &self.playback {
+            Some(playback) => playback
+                .get_input(self.replay.len())
+                .unwrap_or(Movement::Afk),
+            None if self.grid.is_scan_frame() => self.input.take_movement(),
+            None => Movement::Afk,
         };
+        self.replay.record(input);
+        let events = self.grid.step(input);
+
+        if let Some(zone) = self.camera.update(self.grid.get_player_position()) {
+            self.renderer.render_zone(&self.grid, &zone);
+        }
+        let zone = *self.camera.get_zone();
+
+        let mut level_ended = None;
+        for event in events {
+            match event {
+                Event::TileChanged((x, y)) => {
+                    if zone.is_in_zone(x, y) && !self.camera.is_scrolling() {
+                        self.renderer.render_position(&self.grid, (x, y), &zone);
+                    }
+                }
+                Event::PlaySound(sound) => {
+                    if self.save.get_settings().is_sound_enabled() {
+                        self.audio.play(sound);
+                    }
+                }
+                Event::ExitOpened => self.flash_timer = EXIT_FLASH_DURATION,
+                Event::LevelCompleted => level_ended = Some(true),
+                Event::GameOver => level_ended = Some(false),
+            }
+        }
+
+        if self.grid.get_frame() % 2 == 0 && !self.camera.is_scrolling() {
+            self.renderer.render_animated_entities(&self.grid, &zone);
+        }
+
+        self.session.award_extra_lives(&self.grid);
+        self.overlay.render(
+            &self.grid,
+            &self.session,
+            self.renderer.get_context(),
+            self.renderer.get_sprites(),
+        );
+
+        if !self.camera.is_scrolling() {
+            for animation in self.grid.get_animations() {
+                self.renderer.render_animation(animation, &zone);
+            }
+        }
+
+        if self.flash_timer > 0 {
+            self.flash_timer -= 1;
+            if self.flash_timer > 0 {
+                self.renderer.render_flash();
+            } else {
+                self.renderer.render_zone(&self.grid, &zone);
+            }
+        }
+
+        if let Some(completed) = level_ended {
+            self.end_attempt(completed);
+        }
     }
 }
@@ -1,4 +1,3 @@
-use js_sys;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::{closure::Closure, JsValue};
 use wasm_bindgen_futures::JsFuture;
@@ -9,6 +8,12 @@ pub struct ScreenTitle {
     bd_background_image: Option<HtmlImageElement>,
 }
 
+impl Default for ScreenTitle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ScreenTitle {
     pub fn new() -> Self {
         Self {
@@ -139,7 +144,7 @@ impl ScreenTitle {
         context.fill_text(text, x, y).unwrap();
     }
 
-    pub fn render_with_scroll(&self, context: &mut CanvasRenderingContext2d, scroll_offset: f64, show_instructions: bool) {
+    pub fn render_frame(&self, context: &mut CanvasRenderingContext2d, scroll_offset: f64) {
         let canvas = context.canvas().expect("No canvas found");
         let canvas_width = canvas.width() as f64;
         let canvas_height = canvas.height() as f64;
@@ -180,11 +185,81 @@ impl ScreenTitle {
         context.clip();
         self.render_background_mosaic(context, scroll_offset);
         context.restore();
+    }
+
+    pub fn render_with_scroll(
+        &self,
+        context: &mut CanvasRenderingContext2d,
+        scroll_offset: f64,
+        show_instructions: bool,
+    ) {
+        self.render_frame(context, scroll_offset);
         self.render_bd_title(context);
         self.render_credits(context);
-        
+
         if show_instructions {
             self.render_instructions(context);
         }
     }
+
+    pub fn render_menu(
+        &self,
+        context: &mut CanvasRenderingContext2d,
+        scroll_offset: f64,
+        title: &str,
+        entries: &[String],
+        selected: usize,
+    ) {
+        self.render_frame(context, scroll_offset);
+
+        let canvas = context.canvas().expect("No canvas found");
+        let canvas_width = canvas.width() as f64;
+        let canvas_height = canvas.height() as f64;
+
+        let panel_margin = 80.0;
+        context.set_fill_style_str("black");
+        context.fill_rect(
+            panel_margin,
+            panel_margin / 2.0,
+            canvas_width - (2.0 * panel_margin),
+            canvas_height - panel_margin,
+        );
+
+        context.set_font("24px boulderdash, monospace");
+        context.set_fill_style_str("yellow");
+        let title_width = context
+            .measure_text(title)
+            .expect("Failed to measure text")
+            .width();
+        context
+            .fill_text(title, (canvas_width - title_width) / 2.0, 100.0)
+            .unwrap();
+
+        let line_height = 32.0;
+        let visible = ((canvas_height - 200.0) / line_height) as usize;
+        let first = (selected + 1).saturating_sub(visible);
+
+        context.set_font("16px boulderdash, monospace");
+        for (row, entry) in entries.iter().enumerate().skip(first).take(visible) {
+            if row == selected {
+                context.set_fill_style_str("yellow");
+                context
+                    .fill_text(
+                        ">",
+                        panel_margin + 30.0,
+                        160.0 + (row - first) as f64 * line_height,
+                    )
+                    .unwrap();
+            } else {
+                context.set_fill_style_str("white");
+            }
+            context
+                .fill_text(
+                    entry,
+                    panel_margin + 60.0,
+                    160.0 + (row - first) as f64 * line_height,
+                )
+                .unwrap();
+        }
+    }
 }
@@ -0,0 +1,140 @@
+import init, { GameManager, LevelEditorManager } from './out/boulderdash.js';
+
+async function run() {
+    await init();
+
+    const tickDuration = 1000 / 20;
+    const canvas = document.getElementById('canvas');
+    const caveTime = document.getElementById('cave-time');
+    const diamondsRequired = document.getElementById('diamonds-required');
+    const errors = document.getElementById('errors');
+    let editor = await LevelEditorManager.create(40, 22);
+    let game = null;
+    let lastTime = 0;
+
+    function refresh() {
+        caveTime.value = editor.get_cave_time();
+        diamondsRequired.value = editor.get_diamonds_required();
+        errors.textContent = editor.validate().join(' - ');
+    }
+
+    function toCanvas(event) {
+        const rect = canvas.getBoundingClientRect();
+        return [
+            (event.clientX - rect.left) * canvas.width / rect.width,
+            (event.clientY - rect.top) * canvas.height / rect.height,
+        ];
+    }
+
+    canvas.addEventListener('pointerdown', (event) => {
+        if (game) return;
+        canvas.setPointerCapture(event.pointerId);
+        editor.pointer_down(...toCanvas(event));
+    });
+    canvas.addEventListener('pointermove', (event) => {
+        if (!game) editor.pointer_move(...toCanvas(event));
+    });
+    canvas.addEventListener('pointerup', () => {
+        editor.pointer_up();
+        refresh();
+    });
+
+    document.getElementById('brush').addEventListener('change', (event) => {
+        editor.set_brush(event.target.value);
+    });
+    caveTime.addEventListener('change', () => {
+        editor.set_cave_time(Number(caveTime.value));
+        refresh();
+    });
+    diamondsRequired.addEventListener('change', () => {
+        editor.set_diamonds_required(Number(diamondsRequired.value));
+        refresh();
+    });
+    document.getElementById('undo').addEventListener('click', () => {
+        editor.undo();
+        refresh();
+    });
+    document.getElementById('redo').addEventListener('click', () => {
+        editor.redo();
+        refresh();
+    });
+    document.getElementById('open').addEventListener('change', async (event) => {
+        const file = event.target.files[0];
+        if (!file) return;
+        try {
+            editor.load(await file.text());
+        } catch (error) {
+            errors.textContent = error;
+        }
+        refresh();
+    });
+    document.getElementById('export').addEventListener('click', () => {
+        const blob = new Blob([editor.export()], { type: 'text/plain' });
+        const link = document.createElement('a');
+        link.href = URL.createObjectURL(blob);
+        link.download = 'level.bbcff';
+        link.click();
+        URL.revokeObjectURL(link.href);
+    });
+    document.getElementById('play').addEventListener('click', async () => {
+        if (game) return;
+        game = new GameManager();
+        try {
+            await game.start_with_levels(editor.export());
+        } catch (error) {
+            errors.textContent = error;
+            game = null;
+            return;
+        }
+        requestAnimationFrame(gameLoop);
+    });
+
+    document.addEventListener('keydown', (event) => {
+        if (game) {
+            if (event.key === 'Escape') {
+                stopGame();
+            } else if (!event.repeat) {
+                game.key_down(event.key);
+            }
+        } else if (event.ctrlKey && event.key === 'z') {
+            editor.undo();
+            refresh();
+        } else if (event.ctrlKey && event.key === 'y') {
+            editor.redo();
+            refresh();
+        }
+    });
+    document.addEventListener('keyup', (event) => {
+        if (game) {
+            game.key_up(event.key);
+        }
+    });
+
+    window.addEventListener('blur', () => {
+        if (game) {
+            game.release_keys();
+        }
+    });
+
+    function stopGame() {
+        game = null;
+        editor.render();
+    }
+
+    function gameLoop(timestamp) {
+        if (!game) return;
+        if (timestamp - lastTime >= tickDuration) {
+            lastTime = timestamp;
+            game.update();
+            if (game.is_finished()) {
+                stopGame();
+                return;
+            }
+        }
+        requestAnimationFrame(gameLoop);
+    }
+
+    refresh();
+}
+
+run();
@@ -8,71 +8,72 @@ async function run() {
     let game = new GameManager();
     let titleScreen = await TitleScreenManager.create();
     let gameStarted = false;
-    const keysPressed = {};
 
-    let startX, startY, endX, endY;
+    function touchTarget() {
+        return gameStarted ? game : titleScreen;
+    }
 
     document.addEventListener('touchstart', function(event) {
-        const touch = event.touches[0];
-        startX = touch.clientX;
-        startY = touch.clientY;
-    }, false);
-
-    document.addEventListener('touchend', function(event) {
-        handleSwipeRelease();
+        const touch = event.changedTouches[0];
+        touchTarget().touch_start(touch.clientX, touch.clientY, event.touches.length);
     }, false);
 
-    function handleSwipeRelease() {
-        keysPressed['ArrowRight'] = false;
-        keysPressed['ArrowLeft'] = false;
-        keysPressed['ArrowUp'] = false;
-        keysPressed['ArrowDown'] = false;
-    }
-
     document.addEventListener('touchmove', function(event) {
         const touch = event.touches[0];
-        endX = touch.clientX;
-        endY = touch.clientY;
-        handleSwipe();
+        touchTarget().touch_move(touch.clientX, touch.clientY);
     }, false);
 
-    function handleSwipe() {
-        const deltaX = endX - startX;
-        const deltaY = endY - startY;
-        
-        keysPressed['ArrowRight'] = false;
-        keysPressed['ArrowLeft'] = false;
-        keysPressed['ArrowUp'] = false;
-        keysPressed['ArrowDown'] = false;
-
-        if (Math.abs(deltaX) > Math.abs(deltaY)) {
-            if (deltaX > 0) {
-                keysPressed['ArrowRight'] = true;
-            } else {
-                keysPressed['ArrowLeft'] = true;
-            }
+    document.addEventListener('touchend', async function(event) {
+        if (gameStarted) {
+            game.touch_end(event.touches.length);
         } else {
-            if (deltaY > 0) {
-                keysPressed['ArrowDown'] = true;
-            } else {
-                keysPressed['ArrowUp'] = true;
-            }
+            await handleMenuChoice(titleScreen.touch_end(event.touches.length));
         }
+    }, false);
+
+    async function handleMenuChoice(choice) {
+        if (!choice) {
+            return;
+        }
+
+        gameStarted = true;
+        titleScreen.clear_screen();
+        const difficulty = titleScreen.get_difficulty();
+        if (choice.action === 'resume') {
+            await game.resume(difficulty);
+        } else if (choice.action === 'random_cave') {
+            await game.start(1, difficulty);
+            game.play_random_cave(Math.floor(Math.random() * 65536));
+        } else {
+            await game.start(choice.level, difficulty);
+        }
+        requestAnimationFrame(gameLoop);
     }
 
     document.addEventListener('keydown', async (event) => {
         if (!gameStarted) {
-            gameStarted = true;
-            titleScreen.clear_screen(); // Clear the title screen
-            await game.start();
-            requestAnimationFrame(gameLoop);
-        } else {
-            keysPressed[event.key] = true;
+            await handleMenuChoice(titleScreen.key_down(event.key));
+        } else if (!event.repeat) {
+            game.key_down(event.key);
         }
     });
 
     document.addEventListener('keyup', (event) => {
-        keysPressed[event.key] = false;
+        if (gameStarted) {
+            game.key_up(event.key);
+        }
+    });
+
+    window.addEventListener('blur', () => {
+        if (gameStarted) {
+            game.release_keys();
+        }
+    });
+
+    window.addEventListener('pagehide', () => {
+        if (gameStarted) {
+            game.save_progress();
+        }
     });
 
     function titleLoop(timestamp) {
@@ -94,20 +95,13 @@ async function run() {
         if (deltaTime >= tickDuration) {
             lastTime = timestamp;
 
-            if (keysPressed['ArrowRight']) {
-                game.key_down('ArrowRight');
-            }
-            if (keysPressed['ArrowLeft']) {
-                game.key_down('ArrowLeft');
-            }
-            if (keysPressed['ArrowUp']) {
-                game.key_down('ArrowUp');
-            }
-            if (keysPressed['ArrowDown']) {
-                game.key_down('ArrowDown');
-            }
-
             game.update();
+
+            if (game.is_finished()) {
+                gameStarted = false;
+                titleScreen.return_to_menu().then(() => requestAnimationFrame(titleLoop));
+                return;
+            }
         }
 
         requestAnimationFrame(gameLoop);
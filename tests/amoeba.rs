@@ -0,0 +1,56 @@
+use boulderdash::game::{enums::movement::Movement, grid::Grid};
+
+fn run_cave(header: &str, map: &[&str], scans: usize) -> Vec<String> {
+    let mut grid: Grid = format!("version = 1\n{}-\n{}\n", header, map.join("\n"))
+        .parse()
+        .expect("Test cave should parse");
+    for _ in 0..scans * 2 {
+        grid.step(Movement::Afk);
+    }
+    grid.to_level_definition()
+        .get_map()
+        .iter()
+        .map(|row| row.iter().collect())
+        .collect()
+}
+
+fn count(map: &[String], ch: char) -> usize {
+    map.iter()
+        .map(|row| row.chars().filter(|c| *c == ch).count())
+        .sum()
+}
+
+const FIELD: [&str; 7] = [
+    "WWWWWWWWWW",
+    "WP.......W",
+    "W........W",
+    "W...a....W",
+    "W........W",
+    "W........W",
+    "WWWWWWWWXW",
+];
+
+#[test]
+fn amoeba_growth_follows_the_cave_seed() {
+    let header = |seed: u8| format!("seed = {}\namoeba_growth_rate = 64\n", seed);
+    let first = run_cave(&header(3), &FIELD, 30);
+    assert_eq!(first, run_cave(&header(3), &FIELD, 30));
+    assert!(count(&first, 'a') > 1);
+    assert_ne!(first, run_cave(&header(4), &FIELD, 30));
+}
+
+#[test]
+fn an_enclosed_amoeba_turns_into_diamonds() {
+    let cave = ["WWWWWW", "WPXWWW", "WWWWWW", "WWaaWW", "WWWWWW"];
+    let map = run_cave("", &cave, 1);
+    assert_eq!(map[3], "WWddWW");
+    assert_eq!(count(&map, 'a'), 0);
+}
+
+#[test]
+fn an_amoeba_above_its_maximum_size_turns_into_rocks() {
+    let cave = ["WWWWWWW", "WP...XW", "W.aaaaW", "W.....W", "WWWWWWW"];
+    let map = run_cave("amoeba_max_size = 3\n", &cave, 1);
+    assert_eq!(map[2], "W.rrrrW");
+    assert_eq!(count(&map, 'a'), 0);
+}
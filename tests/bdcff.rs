@@ -0,0 +1,57 @@
+use boulderdash::game::level::{bdcff, error::LevelParseErrorKind};
+
+fn game(properties: &str, map: &str) -> String {
+    format!(
+        "[BDCFF]\n[game]\n[cave]\nName=Test\n{}\n[map]\n{}\n[/map]\n[/cave]\n[/game]\n[/BDCFF]\n",
+        properties, map
+    )
+}
+
+const MAP: &str = "WWWWW\nWPdXW\nWWWWW";
+
+#[test]
+fn values_are_picked_for_the_selected_difficulty() {
+    let input = game(
+        "CaveTime=150 140 130 120 110\nDiamondsRequired=10 12 14",
+        MAP,
+    );
+    for (difficulty, cave_time, diamonds) in [(1, 150.0, 10), (2, 140.0, 12), (5, 110.0, 14)] {
+        let levels = bdcff::parse_bdcff(&input, difficulty).unwrap();
+        let header = levels[0].get_header();
+        assert_eq!(header.get_cave_time(), cave_time);
+        assert_eq!(header.get_diamonds_required(), diamonds);
+    }
+}
+
+#[test]
+fn diamond_value_is_split_into_normal_and_extra() {
+    let levels = bdcff::parse_bdcff(&game("DiamondValue=10 15", MAP), 3).unwrap();
+    assert_eq!(levels[0].get_header().get_diamond_value(), 10);
+    assert_eq!(levels[0].get_header().get_extra_diamond_value(), 15);
+}
+
+#[test]
+fn intermission_is_read() {
+    let levels = bdcff::parse_bdcff(&game("Intermission=true", MAP), 1).unwrap();
+    assert!(levels[0].get_header().is_intermission());
+    let levels = bdcff::parse_bdcff(&game("Intermission=false", MAP), 1).unwrap();
+    assert!(!levels[0].get_header().is_intermission());
+}
+
+#[test]
+fn unknown_object_codes_are_reported_at_their_position() {
+    let error = bdcff::parse_bdcff(&game("", "WWWWW\nWP%XW\nWWWWW"), 1).unwrap_err();
+    assert_eq!(error.get_kind(), &LevelParseErrorKind::UnknownTile('%'));
+    assert_eq!((error.get_line(), error.get_column()), (8, 3));
+}
+
+#[test]
+fn caves_drawn_with_objects_are_reported_as_unsupported() {
+    let input = "[BDCFF]\n[game]\n[cave]\nName=Test\n[objects]\nFillRect=0 0 39 21 STEELWALL\n[/objects]\n[/cave]\n[/game]\n[/BDCFF]\n";
+    let error = bdcff::parse_bdcff(input, 1).unwrap_err();
+    assert_eq!(
+        error.get_kind(),
+        &LevelParseErrorKind::UnsupportedSection("objects".to_string())
+    );
+    assert_eq!(error.get_line(), 5);
+}
@@ -0,0 +1,54 @@
+use boulderdash::game::{bomb::Bomb, enums::movement::Movement, grid::Grid, player::Player};
+
+const CAVE: &str = "version = 1\n-\nWWWWWWWWW\nWPb     W\nWWWWWWWXW\n";
+
+fn step_scans(grid: &mut Grid, input: Movement, scans: usize) {
+    for _ in 0..scans {
+        grid.step(input);
+        grid.step(Movement::Afk);
+    }
+}
+
+fn holding_bomb() -> Grid {
+    let mut grid: Grid = CAVE.parse().unwrap();
+    step_scans(&mut grid, Movement::Afk, 45);
+    step_scans(&mut grid, Movement::MoveRight, 1);
+    assert_eq!(grid.get_bombs_held(), 1);
+    grid
+}
+
+fn player(grid: &Grid) -> Player {
+    grid.get_tiles_with_entity::<Player>()[0].clone()
+}
+
+#[test]
+fn dropping_a_bomb_lights_it_on_the_current_tile() {
+    let mut grid = holding_bomb();
+    step_scans(&mut grid, Movement::DropBomb, 1);
+    assert_eq!(grid.get_bombs_held(), 0);
+    assert!(player(&grid).is_on_lit_bomb());
+
+    step_scans(&mut grid, Movement::MoveRight, 1);
+    let bombs = grid.get_tiles_with_entity::<Bomb>();
+    assert_eq!(bombs.len(), 1);
+    assert!(bombs[0].is_lit());
+    assert!(!player(&grid).is_on_lit_bomb());
+}
+
+#[test]
+fn a_bomb_left_behind_explodes_when_its_fuse_runs_out() {
+    let mut grid = holding_bomb();
+    step_scans(&mut grid, Movement::DropBomb, 1);
+    step_scans(&mut grid, Movement::MoveRight, 3);
+    step_scans(&mut grid, Movement::Afk, 20);
+    assert!(grid.get_tiles_with_entity::<Bomb>().is_empty());
+    assert!(!grid.is_game_over());
+}
+
+#[test]
+fn standing_on_a_lit_bomb_is_fatal() {
+    let mut grid = holding_bomb();
+    step_scans(&mut grid, Movement::DropBomb, 1);
+    step_scans(&mut grid, Movement::Afk, 20);
+    assert!(grid.is_game_over());
+}
@@ -0,0 +1,82 @@
+use boulderdash::game::{
+    enums::{animation_type::AnimationType, event::Event, movement::Movement},
+    grid::Grid,
+    level::definition::LevelDefinition,
+    session::Session,
+};
+
+const CAVE: &str = "version = 1\n-\nWWWWW\nW r W\nW . W\nWP  W\nWWWXW\n";
+
+fn step_scans(grid: &mut Grid, input: Movement, scans: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+    for _ in 0..scans {
+        events.extend(grid.step(input));
+        events.extend(grid.step(Movement::Afk));
+    }
+    events
+}
+
+fn is_dying(grid: &Grid) -> bool {
+    grid.get_animations()
+        .iter()
+        .any(|animation| animation.get_animation_type() == AnimationType::Death)
+}
+
+fn crushed(level: &LevelDefinition) -> Grid {
+    let mut grid = Grid::new(level);
+    step_scans(&mut grid, Movement::Afk, 45);
+    step_scans(&mut grid, Movement::MoveRight, 1);
+    step_scans(&mut grid, Movement::MoveUp, 1);
+    step_scans(&mut grid, Movement::MoveDown, 1);
+    step_scans(&mut grid, Movement::Afk, 2);
+    assert!(grid.is_game_over());
+    grid
+}
+
+#[test]
+fn a_falling_rock_plays_the_death_animation() {
+    let level: LevelDefinition = CAVE.parse().unwrap();
+    let grid = crushed(&level);
+    assert!(is_dying(&grid));
+}
+
+#[test]
+fn the_game_over_event_waits_for_the_death_animation() {
+    let level: LevelDefinition = CAVE.parse().unwrap();
+    let mut grid = crushed(&level);
+
+    let mut frames = 0;
+    loop {
+        let events = grid.step(Movement::Afk);
+        frames += 1;
+        if events.iter().any(|event| matches!(event, Event::GameOver)) {
+            break;
+        }
+        assert!(frames < 200, "Game over should be reported");
+    }
+    assert!(frames > 16);
+    assert!(!is_dying(&grid));
+}
+
+#[test]
+fn restarting_after_death_respawns_the_player_and_keeps_the_session() {
+    let level: LevelDefinition = CAVE.parse().unwrap();
+    let mut grid = crushed(&level);
+    step_scans(&mut grid, Movement::Afk, 100);
+
+    let mut session = Session::new();
+    session.end_attempt(&grid, false);
+    assert_eq!(session.get_lives(), 2);
+
+    let mut grid = Grid::new(&level);
+    assert!(!grid.is_game_over());
+    assert!(grid
+        .get_animations()
+        .iter()
+        .any(|animation| animation.get_animation_type() == AnimationType::Spawn));
+
+    step_scans(&mut grid, Movement::Afk, 45);
+    step_scans(&mut grid, Movement::MoveRight, 1);
+    assert!(!grid.is_game_over());
+    assert_eq!(session.get_lives(), 2);
+}
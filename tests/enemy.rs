@@ -0,0 +1,43 @@
+use boulderdash::game::{enums::movement::Movement, grid::Grid};
+
+const CAVE: [&str; 6] = ["WWWWWW", "WPWWXW", "WWWWWW", "W q WW", "W  qWW", "WWWWWW"];
+
+fn run_cave(engine: &str, scans: usize) -> Vec<String> {
+    let level = format!(
+        "version = 1\nengine = \"{}\"\n-\n{}\n",
+        engine,
+        CAVE.join("\n")
+    );
+    let mut grid: Grid = level.parse().expect("Test cave should parse");
+    for _ in 0..scans * 2 {
+        grid.step(Movement::Afk);
+    }
+    grid.to_level_definition()
+        .get_map()
+        .iter()
+        .map(|row| row.iter().collect())
+        .collect()
+}
+
+fn count(map: &[String], ch: char) -> usize {
+    map.iter()
+        .map(|row| row.chars().filter(|c| *c == ch).count())
+        .sum()
+}
+
+#[test]
+fn enemies_heading_for_the_same_tile_are_both_kept() {
+    let passes = run_cave("passes", 1);
+    assert_eq!(passes[4], "W qqWW");
+    assert_eq!(count(&passes, 'q'), 2);
+    assert_eq!(passes, run_cave("scan_order", 1));
+}
+
+#[test]
+fn enemies_are_never_lost_while_moving() {
+    for engine in ["passes", "scan_order"] {
+        for scans in 1..20 {
+            assert_eq!(count(&run_cave(engine, scans), 'q'), 2, "{} engine", engine);
+        }
+    }
+}
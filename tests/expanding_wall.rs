@@ -0,0 +1,30 @@
+use boulderdash::game::{enums::movement::Movement, grid::Grid};
+
+#[test]
+fn expanding_walls_grow_into_empty_tiles_until_blocked() {
+    let mut grid: Grid =
+        "version = 1\n-\nWWWWWWWWW\nWP      W\nW  .x  .W\nW       W\nW v     W\nW .     W\nWWWWWWWXW\n"
+            .parse()
+            .unwrap();
+    for _ in 0..20 {
+        grid.step(Movement::Afk);
+    }
+    let map: Vec<String> = grid
+        .to_level_definition()
+        .get_map()
+        .iter()
+        .map(|row| row.iter().collect())
+        .collect();
+    assert_eq!(
+        map,
+        [
+            "WWWWWWWWW",
+            "WPv     W",
+            "W v.xxx.W",
+            "W v     W",
+            "W v     W",
+            "W .     W",
+            "WWWWWWWXW",
+        ]
+    );
+}
@@ -0,0 +1,58 @@
+use boulderdash::game::level::{
+    definition::LevelDefinition,
+    generator::{generate_random_cave, generate_solvable_cave, is_solvable},
+};
+
+#[test]
+fn the_same_seed_generates_the_same_cave() {
+    for seed in [0, 1, 42, 1234, u16::MAX] {
+        assert_eq!(
+            generate_random_cave(seed).to_level_string(),
+            generate_random_cave(seed).to_level_string()
+        );
+        assert_eq!(
+            generate_solvable_cave(seed).map(|level| level.to_level_string()),
+            generate_solvable_cave(seed).map(|level| level.to_level_string())
+        );
+    }
+}
+
+#[test]
+fn different_seeds_generate_different_caves() {
+    assert_ne!(
+        generate_random_cave(1).to_level_string(),
+        generate_random_cave(2).to_level_string()
+    );
+}
+
+#[test]
+fn solvable_caves_are_solvable() {
+    for seed in 0..50 {
+        let level = generate_solvable_cave(seed).expect("A solvable cave should be found");
+        assert!(
+            is_solvable(&level),
+            "cave for seed {} is not solvable",
+            seed
+        );
+    }
+}
+
+#[test]
+fn caves_without_enough_reachable_diamonds_are_not_solvable() {
+    let level: LevelDefinition = "version = 1\ndiamonds_required = 1\n-\nWWWWWW\nWPXWdW\nWWWWWW\n"
+        .parse()
+        .unwrap();
+    assert!(!is_solvable(&level));
+}
+
+#[test]
+fn rocks_block_the_way_to_diamonds_and_exit() {
+    let level = |map: &str| -> LevelDefinition {
+        format!("version = 1\ndiamonds_required = 1\n-\n{}\n", map)
+            .parse()
+            .unwrap()
+    };
+    assert!(is_solvable(&level("WWWWWW\nWPdXWW\nWWWWWW")));
+    assert!(!is_solvable(&level("WWWWWW\nWPrdXW\nWWWWWW")));
+    assert!(!is_solvable(&level("WWWWWW\nWPdrXW\nWWWWWW")));
+}
@@ -0,0 +1,63 @@
+use boulderdash::game::{
+    enums::{event::Event, movement::Movement, sound::Sound},
+    grid::Grid,
+};
+
+fn spawn(level: &str) -> Grid {
+    let mut grid: Grid = level.parse().unwrap();
+    for _ in 0..90 {
+        grid.step(Movement::Afk);
+    }
+    grid
+}
+
+fn step_scans(grid: &mut Grid, input: Movement, scans: usize) -> Vec<Event> {
+    let mut events = vec![];
+    for _ in 0..scans {
+        events.extend(grid.step(input));
+        events.extend(grid.step(Movement::Afk));
+    }
+    events
+}
+
+#[test]
+fn claiming_the_last_diamond_opens_the_exit() {
+    let mut grid = spawn("version = 1\ndiamonds_required = 1\n-\nWWWWW\nWPdXW\nWWWWW\n");
+    let events = step_scans(&mut grid, Movement::MoveRight, 1);
+    assert!(events.contains(&Event::PlaySound(Sound::ClaimDiamond)));
+    assert!(events.contains(&Event::ExitOpened));
+    assert!(events.contains(&Event::PlaySound(Sound::ExitOpen)));
+    assert!(events.contains(&Event::TileChanged((3, 1))));
+    assert_eq!(grid.get_diamonds_claimed(), 1);
+}
+
+#[test]
+fn entering_the_open_exit_completes_the_level() {
+    let mut grid =
+        spawn("version = 1\ndiamonds_required = 0\ncave_time = 60\n-\nWWWW\nWPXW\nWWWW\n");
+    step_scans(&mut grid, Movement::MoveRight, 1);
+    assert!(grid.is_level_completed());
+    let events: Vec<Event> = (0..200).flat_map(|_| grid.step(Movement::Afk)).collect();
+    assert!(events.contains(&Event::PlaySound(Sound::TimeBonus)));
+    assert_eq!(events.last(), Some(&Event::LevelCompleted));
+}
+
+#[test]
+fn a_falling_rock_ends_the_game() {
+    let mut grid: Grid = "version = 1\n-\nWWWWW\nW r W\nW   W\nW   W\nW P W\nWWWXW\n"
+        .parse()
+        .unwrap();
+    let events: Vec<Event> = (0..200).flat_map(|_| grid.step(Movement::Afk)).collect();
+    assert!(events.contains(&Event::PlaySound(Sound::Death)));
+    assert!(events.contains(&Event::GameOver));
+    assert!(grid.is_game_over());
+}
+
+#[test]
+fn moving_reports_the_changed_tiles() {
+    let mut grid = spawn("version = 1\n-\nWWWWWW\nWP. XW\nWWWWWW\n");
+    let events = step_scans(&mut grid, Movement::MoveRight, 1);
+    assert!(events.contains(&Event::TileChanged((1, 1))));
+    assert!(events.contains(&Event::TileChanged((2, 1))));
+    assert!(events.contains(&Event::PlaySound(Sound::PlayerMove)));
+}
@@ -0,0 +1,97 @@
+use boulderdash::game::{
+    enums::{input_action::InputAction, movement::Movement},
+    input::{InputBindings, InputQueue},
+};
+
+#[test]
+fn a_tap_is_latched_until_taken() {
+    let mut input = InputQueue::new();
+    input.press(Movement::MoveLeft);
+    input.release(Movement::MoveLeft);
+    assert_eq!(input.take_movement(), Movement::MoveLeft);
+    assert_eq!(input.take_movement(), Movement::Afk);
+}
+
+#[test]
+fn a_held_key_repeats_until_released() {
+    let mut input = InputQueue::new();
+    input.press(Movement::MoveUp);
+    assert_eq!(input.take_movement(), Movement::MoveUp);
+    assert_eq!(input.take_movement(), Movement::MoveUp);
+    input.release(Movement::MoveUp);
+    assert_eq!(input.take_movement(), Movement::Afk);
+}
+
+#[test]
+fn the_latest_held_key_wins_and_releasing_it_falls_back() {
+    let mut input = InputQueue::new();
+    input.press(Movement::MoveUp);
+    input.press(Movement::MoveRight);
+    assert_eq!(input.take_movement(), Movement::MoveRight);
+    input.release(Movement::MoveRight);
+    assert_eq!(input.take_movement(), Movement::MoveUp);
+}
+
+#[test]
+fn snapping_applies_while_held() {
+    let mut input = InputQueue::new();
+    input.press_snap();
+    input.press(Movement::MoveDown);
+    assert_eq!(input.take_movement(), Movement::MoveDown.snap());
+    input.release_snap();
+    assert_eq!(input.take_movement(), Movement::MoveDown);
+}
+
+#[test]
+fn a_bomb_press_is_taken_once_before_movement() {
+    let mut input = InputQueue::new();
+    input.press(Movement::MoveLeft);
+    input.press_bomb();
+    assert_eq!(input.take_movement(), Movement::DropBomb);
+    assert_eq!(input.take_movement(), Movement::MoveLeft);
+}
+
+#[test]
+fn clearing_releases_everything() {
+    let mut input = InputQueue::new();
+    input.press(Movement::MoveLeft);
+    input.press_snap();
+    input.press_bomb();
+    input.clear();
+    assert_eq!(input.take_movement(), Movement::Afk);
+}
+
+#[test]
+fn default_bindings_support_qwerty_and_azerty_movement() {
+    let bindings = InputBindings::default();
+    for (keys, direction) in [
+        (["w", "z"], Movement::MoveUp),
+        (["a", "q"], Movement::MoveLeft),
+        (["s", "s"], Movement::MoveDown),
+        (["d", "d"], Movement::MoveRight),
+    ] {
+        for key in keys {
+            assert_eq!(bindings.get_action(key), Some(InputAction::Move(direction)));
+        }
+    }
+    for key in bindings.get_keys(InputAction::Quit) {
+        assert_ne!(
+            key.chars().count(),
+            1,
+            "quit is bound to the letter {}",
+            key
+        );
+    }
+}
+
+#[test]
+fn rebinding_a_used_key_reports_the_displaced_action() {
+    let mut bindings = InputBindings::default();
+    assert_eq!(
+        bindings.rebind("p", InputAction::Bomb),
+        Some(InputAction::Pause)
+    );
+    assert_eq!(bindings.get_action("p"), Some(InputAction::Bomb));
+    assert_eq!(bindings.rebind("x", InputAction::Bomb), None);
+    assert_eq!(bindings.rebind("x", InputAction::Bomb), None);
+}
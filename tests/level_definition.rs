@@ -0,0 +1,134 @@
+use boulderdash::game::level::{
+    definition::LevelDefinition,
+    error::{LevelParseErrorKind, LevelValidationError},
+    generator::generate_solvable_cave,
+};
+
+const CAVE: &str = "version = 1
+name = \"Round trip\"
+author = \"Tester\"
+cave_time = 90.0
+diamonds_required = 3
+diamond_value = 15
+extra_diamond_value = 20
+amoeba_max_size = 100
+amoeba_growth_rate = 8
+amoeba_time = 30.0
+magic_wall_time = 20.0
+slime_permeability = 64
+seed = 7
+intermission = true
+engine = \"scan_order\"
+-
+WWWWWWWWWW
+WP.r d q W
+W wxvs M W
+W aBb d XW
+WWWWWWWWWW
+";
+
+#[test]
+fn level_string_round_trips() {
+    let level: LevelDefinition = CAVE.parse().unwrap();
+    let text = level.to_level_string();
+    let reparsed: LevelDefinition = text.parse().unwrap();
+    assert_eq!(reparsed, level);
+    assert_eq!(reparsed.to_level_string(), text);
+    assert_eq!(reparsed.get_header().get_name(), "Round trip");
+    assert_eq!(reparsed.get_header().get_slime_permeability(), 64);
+    assert!(reparsed.get_header().is_intermission());
+}
+
+#[test]
+fn generated_caves_round_trip() {
+    for seed in 0..10 {
+        let level = generate_solvable_cave(seed).unwrap();
+        let reparsed: LevelDefinition = level.to_level_string().parse().unwrap();
+        assert_eq!(reparsed, level);
+    }
+}
+
+fn parse_error(input: &str) -> (usize, usize, LevelParseErrorKind) {
+    let error = input.parse::<LevelDefinition>().unwrap_err();
+    (
+        error.get_line(),
+        error.get_column(),
+        error.get_kind().clone(),
+    )
+}
+
+fn validation_errors(map: &str, diamonds_required: i32) -> Vec<LevelValidationError> {
+    let level: LevelDefinition = format!(
+        "version = 1\ndiamonds_required = {}\n-\n{}\n",
+        diamonds_required, map
+    )
+    .parse()
+    .unwrap();
+    level.validate().err().unwrap_or_default()
+}
+
+#[test]
+fn unknown_tiles_are_reported_at_their_position() {
+    assert_eq!(
+        parse_error("version = 1\n-\nWWWW\nWPZW\nWWWW\n"),
+        (4, 3, LevelParseErrorKind::UnknownTile('Z'))
+    );
+}
+
+#[test]
+fn bad_toml_is_reported_at_its_position() {
+    let (line, column, kind) = parse_error("version = 1\n\nname = \"a\"\nbogus = = 2\n-\nWW\n");
+    assert_eq!((line, column), (4, 9));
+    assert!(matches!(kind, LevelParseErrorKind::InvalidHeader(_)));
+
+    let (line, column, kind) = parse_error("version = 1\nname = 3\n-\nWWWW\n");
+    assert_eq!((line, column), (2, 8));
+    assert!(matches!(kind, LevelParseErrorKind::InvalidHeader(_)));
+}
+
+#[test]
+fn a_missing_separator_is_reported() {
+    assert_eq!(
+        parse_error("version = 1\nWWWW\nWPXW\n"),
+        (3, 1, LevelParseErrorKind::MissingSeparator)
+    );
+}
+
+#[test]
+fn multiple_players_are_rejected() {
+    assert_eq!(
+        validation_errors("WWWWW\nWPPXW\nWWWWW", 0),
+        vec![LevelValidationError::MultiplePlayers(2)]
+    );
+}
+
+#[test]
+fn an_unreachable_exit_is_rejected() {
+    assert_eq!(
+        validation_errors("WWWWW\nWPWXW\nWWWWW", 0),
+        vec![LevelValidationError::UnreachableExit]
+    );
+}
+
+#[test]
+fn too_few_diamonds_are_rejected() {
+    assert_eq!(
+        validation_errors("WWWWWW\nWPddXW\nWWWWWW", 3),
+        vec![LevelValidationError::NotEnoughDiamonds {
+            required: 3,
+            available: 2
+        }]
+    );
+}
+
+#[test]
+fn only_diamonds_reachable_from_the_player_are_available() {
+    assert_eq!(
+        validation_errors("WWWWWWWW\nWPdXWdBW\nWWWWWWWW", 2),
+        vec![LevelValidationError::NotEnoughDiamonds {
+            required: 2,
+            available: 1
+        }]
+    );
+    assert!(validation_errors("WWWWWW\nWPdXBW\nWWWWWW", 10).is_empty());
+}
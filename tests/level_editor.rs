@@ -0,0 +1,46 @@
+use boulderdash::game::level::editor::LevelEditor;
+
+#[test]
+fn painting_outside_the_map_adds_no_undo_step() {
+    let mut editor = LevelEditor::new(6, 5);
+    editor.begin_stroke();
+    assert!(!editor.paint(-1, 2, 'r'));
+    assert!(!editor.paint(2, 9, 'r'));
+    editor.end_stroke();
+    assert!(!editor.can_undo());
+}
+
+#[test]
+fn painting_the_same_tile_adds_no_undo_step() {
+    let mut editor = LevelEditor::new(6, 5);
+    editor.begin_stroke();
+    assert!(!editor.paint(2, 2, '.'));
+    editor.end_stroke();
+    assert!(!editor.can_undo());
+}
+
+#[test]
+fn a_stroke_is_undone_as_one_step() {
+    let mut editor = LevelEditor::new(6, 5);
+    let original = editor.get_level().clone();
+    editor.begin_stroke();
+    assert!(editor.paint(2, 2, 'r'));
+    assert!(editor.paint(3, 2, 'r'));
+    editor.end_stroke();
+    assert!(editor.undo());
+    assert_eq!(editor.get_level(), &original);
+    assert!(!editor.can_undo());
+}
+
+#[test]
+fn unchanged_header_values_add_no_undo_step() {
+    let mut editor = LevelEditor::new(6, 5);
+    let cave_time = editor.get_level().get_header().get_cave_time();
+    assert!(!editor.set_cave_time(cave_time));
+    assert!(!editor.set_diamonds_required(0));
+    assert!(!editor.set_diamonds_required(-4));
+    assert!(!editor.can_undo());
+
+    assert!(editor.set_diamonds_required(12));
+    assert!(editor.can_undo());
+}
@@ -0,0 +1,85 @@
+use boulderdash::game::{
+    enums::movement::Movement, grid::Grid, level::definition::LevelDefinition, replay::Replay,
+};
+
+const CAVE: &str = "version = 1
+diamonds_required = 2
+amoeba_time = 1.0
+-
+WWWWWWWWWWWW
+WP.r.d.....W
+W...rd. q  W
+W.. . r    W
+W.d... .. aW
+W.r.. .. ..W
+WWWWWWWWWWXW
+";
+
+type Tick = (String, i32, i32);
+
+fn tick(grid: &Grid) -> Tick {
+    (
+        grid.to_level_string(),
+        grid.get_score(),
+        grid.get_diamonds_claimed(),
+    )
+}
+
+fn inputs() -> Vec<Movement> {
+    let mut inputs = vec![Movement::Afk; 90];
+    for direction in [
+        Movement::MoveRight,
+        Movement::MoveDown,
+        Movement::MoveRight,
+        Movement::MoveDown,
+        Movement::MoveDown,
+        Movement::MoveRight,
+        Movement::MoveUp,
+        Movement::MoveLeft,
+    ] {
+        inputs.extend([direction, Movement::Afk, direction, Movement::Afk]);
+    }
+    inputs.extend([Movement::Afk; 60]);
+    inputs
+}
+
+fn record(grid: &mut Grid, replay: &mut Replay) -> Vec<Tick> {
+    let mut ticks = Vec::new();
+    for input in inputs() {
+        replay.record(input);
+        grid.step(input);
+        ticks.push(tick(grid));
+    }
+    ticks
+}
+
+fn assert_plays_back(replay: &Replay, ticks: &[Tick]) {
+    let text = serde_json::to_string(replay).unwrap();
+    let replay: Replay = serde_json::from_str(&text).unwrap();
+    let mut grid = replay.create_grid().expect("Replay cave should load");
+    for (index, expected) in ticks.iter().enumerate() {
+        grid.step(replay.get_input(index).unwrap());
+        assert_eq!(&tick(&grid), expected, "replay diverged at tick {}", index);
+    }
+}
+
+#[test]
+fn a_recorded_replay_reproduces_every_tick() {
+    let level: LevelDefinition = CAVE.parse().unwrap();
+    let mut grid = Grid::new(&level);
+    let mut replay = Replay::new(1, 1, None, &level);
+    let ticks = record(&mut grid, &mut replay);
+    assert_plays_back(&replay, &ticks);
+}
+
+#[test]
+fn a_replay_of_a_resumed_game_reproduces_every_tick() {
+    let mut grid: Grid = CAVE.parse().unwrap();
+    for _ in 0..57 {
+        grid.step(Movement::Afk);
+    }
+    let mut grid = Grid::from_snapshot(&grid.to_snapshot()).unwrap();
+    let mut replay = Replay::from_snapshot(1, 1, None, grid.to_snapshot());
+    let ticks = record(&mut grid, &mut replay);
+    assert_plays_back(&replay, &ticks);
+}
@@ -0,0 +1,104 @@
+use std::{cell::RefCell, rc::Rc};
+
+use boulderdash::game::{
+    grid::Grid,
+    interfaces::storage::Storage,
+    memory_storage::MemoryStorage,
+    save::{SaveManager, SavedGame},
+    session::Session,
+};
+
+#[derive(Clone, Default)]
+struct SharedStorage {
+    inner: Rc<RefCell<MemoryStorage>>,
+}
+
+impl Storage for SharedStorage {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.inner.borrow().get_item(key)
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) {
+        self.inner.borrow_mut().set_item(key, value);
+    }
+
+    fn remove_item(&mut self, key: &str) {
+        self.inner.borrow_mut().remove_item(key);
+    }
+}
+
+#[test]
+fn managers_sharing_storage_keep_each_others_progress() {
+    let storage = SharedStorage::default();
+    let mut menu = SaveManager::new(Box::new(storage.clone()));
+    let mut game = SaveManager::new(Box::new(storage.clone()));
+
+    game.record_result(1, 120, 42.0);
+    game.unlock_level(2);
+
+    let mut settings = menu.get_settings().clone();
+    settings.set_sound_enabled(false);
+    menu.set_settings(settings);
+
+    let reloaded = SaveManager::new(Box::new(storage));
+    assert_eq!(reloaded.get_unlocked_levels(), 2);
+    assert_eq!(
+        reloaded.get_record(1).map(|r| r.get_best_score()),
+        Some(120)
+    );
+    assert!(!reloaded.get_settings().is_sound_enabled());
+}
+
+#[test]
+fn a_fresh_storage_starts_with_defaults() {
+    let save = SaveManager::new(Box::new(MemoryStorage::new()));
+    assert_eq!(save.get_unlocked_levels(), 1);
+    assert!(save.is_unlocked(1));
+    assert!(!save.is_unlocked(2));
+    assert!(save.get_record(1).is_none());
+    assert!(save.get_saved_game().is_none());
+}
+
+#[test]
+fn records_keep_the_best_score_and_time() {
+    let mut save = SaveManager::new(Box::new(MemoryStorage::new()));
+    save.record_result(3, 100, 50.0);
+    save.record_result(3, 80, 40.0);
+    let record = save.get_record(3).unwrap();
+    assert_eq!(record.get_best_score(), 100);
+    assert_eq!(record.get_best_time(), 40.0);
+}
+
+#[test]
+fn saved_games_survive_a_reload_and_are_taken_once() {
+    let storage = SharedStorage::default();
+    let mut save = SaveManager::new(Box::new(storage.clone()));
+    let grid: Grid = "version = 1\n-\nWWWW\nWPXW\nWWWW\n".parse().unwrap();
+    save.set_saved_game(SavedGame::new(2, 3, Session::new(), grid.to_snapshot()));
+
+    let mut reloaded = SaveManager::new(Box::new(storage.clone()));
+    assert_eq!(
+        reloaded.get_saved_game().map(|game| game.get_level()),
+        Some(2)
+    );
+    assert_eq!(
+        reloaded
+            .get_saved_game()
+            .and_then(|game| game.get_difficulty()),
+        Some(3)
+    );
+    assert!(reloaded.take_saved_game().is_some());
+    assert!(SaveManager::new(Box::new(storage))
+        .get_saved_game()
+        .is_none());
+}
+
+#[test]
+fn saved_games_without_a_difficulty_still_load() {
+    let grid: Grid = "version = 1\n-\nWWWW\nWPXW\nWWWW\n".parse().unwrap();
+    let saved_game = SavedGame::new(1, 2, Session::new(), grid.to_snapshot());
+    let mut value = serde_json::to_value(&saved_game).unwrap();
+    value.as_object_mut().unwrap().remove("difficulty");
+    let saved_game: SavedGame = serde_json::from_value(value).unwrap();
+    assert_eq!(saved_game.get_difficulty(), None);
+}
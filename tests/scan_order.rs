@@ -0,0 +1,98 @@
+use boulderdash::game::{
+    enums::{engine_mode::EngineMode, movement::Movement},
+    grid::Grid,
+    level::{bdcff, definition::LevelDefinition},
+};
+
+fn run_cave(map: &[&str], engine: &str, scans: usize) -> Vec<String> {
+    let level = format!(
+        "version = 1\nengine = \"{}\"\n-\n{}\n",
+        engine,
+        map.join("\n")
+    );
+    let mut grid: Grid = level.parse().expect("Test cave should parse");
+    for _ in 0..scans * 2 {
+        grid.step(Movement::Afk);
+    }
+    grid.to_level_definition()
+        .get_map()
+        .iter()
+        .map(|row| row.iter().collect())
+        .collect()
+}
+
+fn count(map: &[String], ch: char) -> usize {
+    map.iter()
+        .map(|row| row.chars().filter(|c| *c == ch).count())
+        .sum()
+}
+
+#[test]
+fn engine_defaults_to_passes() {
+    let level: LevelDefinition = "version = 1\n-\nWWWW\nWPXW\nWWWW\n".parse().unwrap();
+    assert_eq!(level.get_header().get_engine(), EngineMode::Passes);
+
+    let level: LevelDefinition = "version = 1\nengine = \"scan_order\"\n-\nWWWW\nWPXW\nWWWW\n"
+        .parse()
+        .unwrap();
+    assert_eq!(level.get_header().get_engine(), EngineMode::ScanOrder);
+}
+
+#[test]
+fn bdcff_engine_property_selects_scan_order() {
+    let input = "[BDCFF]\n[game]\nEngine=BD1\n[cave]\nName=Test\n[map]\nWWWW\nWPXW\nWWWW\n[/map]\n[/cave]\n[/game]\n[/BDCFF]\n";
+    let levels = bdcff::parse_bdcff(input, 1).unwrap();
+    assert_eq!(levels[0].get_header().get_engine(), EngineMode::ScanOrder);
+}
+
+#[test]
+fn bdcff_engine_property_keeps_passes_and_rejects_unknown_engines() {
+    let cave = |engine: &str| {
+        format!("[BDCFF]\n[game]\nEngine={}\n[cave]\nName=Test\n[map]\nWWWW\nWPXW\nWWWW\n[/map]\n[/cave]\n[/game]\n[/BDCFF]\n", engine)
+    };
+    let levels = bdcff::parse_bdcff(&cave("Passes"), 1).unwrap();
+    assert_eq!(levels[0].get_header().get_engine(), EngineMode::Passes);
+    assert!(bdcff::parse_bdcff(&cave("Unknown"), 1).is_err());
+}
+
+#[test]
+fn rocks_rolling_into_the_same_gap_are_both_kept() {
+    let cave = [
+        "WWWWWWW", "WP   XW", "W     W", "WWr rWW", "WWr rWW", "W.. ..W", "WWWWWWW",
+    ];
+
+    let map = run_cave(&cave, "scan_order", 1);
+    assert_eq!(map[3], "WW rrWW");
+    assert_eq!(count(&map, 'r'), 4);
+
+    let map = run_cave(&cave, "scan_order", 2);
+    assert_eq!(map[3], "WW  rWW");
+    assert_eq!(map[4], "WWrrrWW");
+    assert_eq!(count(&map, 'r'), 4);
+}
+
+#[test]
+fn upper_left_objects_are_scanned_first() {
+    let cave = [
+        "WWWWWWW", "WP   XW", "W     W", "WWd rWW", "WWr rWW", "W.. ..W", "WWWWWWW",
+    ];
+
+    let map = run_cave(&cave, "scan_order", 2);
+    assert_eq!(map[4], "WWrdrWW");
+
+    let map = run_cave(&cave, "passes", 2);
+    assert_eq!(map[4], "WWrrrWW");
+}
+
+#[test]
+fn moved_objects_are_not_scanned_twice() {
+    let cave = [
+        "WWWWW", "WP XW", "WWrWW", "WWrWW", "WW WW", "WW WW", "WW WW", "WW WW", "WWWWW",
+    ];
+
+    let map = run_cave(&cave, "scan_order", 1);
+    assert_eq!(&map[2..5], ["WWrWW", "WW WW", "WWrWW"]);
+
+    let map = run_cave(&cave, "scan_order", 3);
+    assert_eq!(&map[2..7], ["WW WW", "WW WW", "WWrWW", "WW WW", "WWrWW"]);
+}
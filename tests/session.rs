@@ -0,0 +1,86 @@
+use boulderdash::game::{
+    enums::{event::Event, movement::Movement},
+    grid::Grid,
+    session::Session,
+};
+
+const DIAMOND_CAVE: &str = "version = 1\ndiamonds_required = 1\n-\nWWWWW\nWPdXW\nWWWWW\n";
+
+fn step_scans(grid: &mut Grid, input: Movement, scans: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+    for _ in 0..scans {
+        events.extend(grid.step(input));
+        events.extend(grid.step(Movement::Afk));
+    }
+    events
+}
+
+fn failed_attempt(header: &str) -> Grid {
+    let level = format!(
+        "version = 1\ncave_time = 1.0\n{}-\nWWWWW\nWP XW\nWWWWW\n",
+        header
+    );
+    let mut grid: Grid = level.parse().unwrap();
+    let events = step_scans(&mut grid, Movement::Afk, 200);
+    assert!(grid.is_game_over());
+    assert!(events.iter().any(|event| matches!(event, Event::GameOver)));
+    grid
+}
+
+fn completed_attempt() -> Grid {
+    let mut grid: Grid = DIAMOND_CAVE.parse().unwrap();
+    step_scans(&mut grid, Movement::Afk, 45);
+    step_scans(&mut grid, Movement::MoveRight, 4);
+    assert!(grid.is_level_completed());
+    grid
+}
+
+#[test]
+fn dying_costs_a_life() {
+    let mut session = Session::new();
+    assert_eq!(session.get_lives(), 3);
+
+    session.end_attempt(&failed_attempt(""), false);
+    assert_eq!(session.get_lives(), 2);
+    assert!(!session.is_game_over());
+}
+
+#[test]
+fn dying_in_an_intermission_is_free() {
+    let mut session = Session::new();
+    session.end_attempt(&failed_attempt("intermission = true\n"), false);
+    assert_eq!(session.get_lives(), 3);
+}
+
+#[test]
+fn the_game_is_over_when_the_last_life_is_lost() {
+    let mut session = Session::new();
+    for lives in (0..3).rev() {
+        assert!(!session.is_game_over());
+        session.end_attempt(&failed_attempt(""), false);
+        assert_eq!(session.get_lives(), lives);
+    }
+    assert!(session.is_game_over());
+}
+
+#[test]
+fn score_carries_over_between_caves() {
+    let mut session = Session::new();
+    let first = completed_attempt();
+    assert!(first.get_score() > 0);
+    session.end_attempt(&first, true);
+    assert_eq!(session.get_score(), first.get_score());
+    assert_eq!(session.get_lives(), 3);
+
+    let mut second: Grid = DIAMOND_CAVE.parse().unwrap();
+    step_scans(&mut second, Movement::Afk, 45);
+    step_scans(&mut second, Movement::MoveRight, 1);
+    assert_eq!(
+        session.get_total_score(&second),
+        first.get_score() + second.get_score()
+    );
+
+    session.end_attempt(&second, false);
+    assert_eq!(session.get_score(), first.get_score() + second.get_score());
+    assert_eq!(session.get_lives(), 2);
+}
@@ -0,0 +1,62 @@
+use boulderdash::game::{enums::movement::Movement, grid::Grid};
+
+const MAP: &str = "WWWWWWW\nWPr dWW\nW s sWW\nW    WW\nW    XW\nWWWWWWW";
+
+fn cave(permeability: u8, seed: u8) -> Grid {
+    format!(
+        "version = 1\nslime_permeability = {}\nseed = {}\n-\n{}\n",
+        permeability, seed, MAP
+    )
+    .parse()
+    .unwrap()
+}
+
+fn map(grid: &Grid) -> Vec<String> {
+    grid.to_level_definition()
+        .get_map()
+        .iter()
+        .map(|row| row.iter().collect())
+        .collect()
+}
+
+fn scans_until_through(grid: &mut Grid, limit: usize) -> Option<usize> {
+    (1..=limit).find(|_| {
+        grid.step(Movement::Afk);
+        grid.step(Movement::Afk);
+        map(grid)[1] == "WP   WW"
+    })
+}
+
+#[test]
+fn rocks_and_diamonds_seep_through_open_slime() {
+    let mut grid = cave(255, 0);
+    assert!(scans_until_through(&mut grid, 20).is_some());
+    for _ in 0..20 {
+        grid.step(Movement::Afk);
+    }
+    let map = map(&grid);
+    assert_eq!(map[2], "W s sWW");
+    assert_eq!(map[4], "W r dXW");
+}
+
+#[test]
+fn closed_slime_holds_everything() {
+    let mut grid = cave(0, 0);
+    assert_eq!(scans_until_through(&mut grid, 50), None);
+    assert_eq!(map(&grid)[1], "WPr dWW");
+}
+
+#[test]
+fn slime_permeability_follows_the_cave_seed() {
+    assert_eq!(scans_until_through(&mut cave(64, 0), 200), Some(1));
+    assert_eq!(scans_until_through(&mut cave(64, 5), 200), Some(8));
+    assert_eq!(scans_until_through(&mut cave(64, 5), 200), Some(8));
+
+    let mut a = cave(64, 7);
+    let mut b = cave(64, 7);
+    for _ in 0..200 {
+        a.step(Movement::Afk);
+        b.step(Movement::Afk);
+        assert_eq!(map(&a), map(&b));
+    }
+}
@@ -0,0 +1,39 @@
+use boulderdash::game::{enums::movement::Movement, grid::Grid};
+
+fn spawn() -> Grid {
+    let mut grid: Grid = "version = 1\n-\nWWWWWW\nW.P dW\nWWWWXW\n".parse().unwrap();
+    for _ in 0..90 {
+        grid.step(Movement::Afk);
+    }
+    grid
+}
+
+fn snap(grid: &mut Grid, direction: Movement) {
+    grid.step(direction.snap());
+    grid.step(Movement::Afk);
+}
+
+fn row(grid: &Grid) -> String {
+    grid.to_level_definition().get_map()[1].iter().collect()
+}
+
+#[test]
+fn snapping_removes_dirt_without_moving() {
+    let mut grid = spawn();
+    snap(&mut grid, Movement::MoveLeft);
+    assert_eq!(row(&grid), "W P dW");
+    assert_eq!(grid.get_player_position(), (2, 1));
+}
+
+#[test]
+fn snapping_collects_a_diamond_without_moving() {
+    let mut grid = spawn();
+    grid.step(Movement::MoveRight);
+    grid.step(Movement::Afk);
+    assert_eq!(grid.get_player_position(), (3, 1));
+
+    snap(&mut grid, Movement::MoveRight);
+    assert_eq!(row(&grid), "W. P W");
+    assert_eq!(grid.get_player_position(), (3, 1));
+    assert_eq!(grid.get_diamonds_claimed(), 1);
+}
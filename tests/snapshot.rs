@@ -0,0 +1,124 @@
+use std::fs;
+
+use boulderdash::game::{bomb::Bomb, enums::movement::Movement, grid::Grid, snapshot::Snapshot};
+
+const GOLDEN_SCANS: usize = 40;
+
+fn run_golden(name: &str) {
+    let path = format!("tests/golden/{}.bbcff", name);
+    let expected_path = format!("tests/golden/{}.expected.bbcff", name);
+    let mut grid: Grid = fs::read_to_string(&path)
+        .expect("Golden cave should exist")
+        .parse()
+        .expect("Golden cave should parse");
+    for _ in 0..GOLDEN_SCANS * 2 {
+        grid.step(Movement::Afk);
+    }
+    let actual = grid.to_level_string();
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&expected_path, &actual).expect("Could not write golden file");
+    }
+    let expected = fs::read_to_string(&expected_path).expect("Expected golden file should exist");
+    assert_eq!(actual, expected, "{} differs from its golden file", name);
+}
+
+fn resume(grid: &Grid) -> Grid {
+    let text = serde_json::to_string(&grid.to_snapshot()).unwrap();
+    let snapshot: Snapshot = serde_json::from_str(&text).unwrap();
+    Grid::from_snapshot(&snapshot).expect("Snapshot should load")
+}
+
+fn assert_resume_matches(level: &str, before: &[Movement], after: &[Movement]) -> (Grid, Grid) {
+    let mut live: Grid = level.parse().unwrap();
+    for input in before {
+        live.step(*input);
+    }
+    let mut resumed = resume(&live);
+    for input in after {
+        live.step(*input);
+        resumed.step(*input);
+        assert_eq!(resumed.to_level_string(), live.to_level_string());
+    }
+    assert_eq!(resumed.get_score(), live.get_score());
+    assert_eq!(resumed.get_diamonds_claimed(), live.get_diamonds_claimed());
+    assert_eq!(resumed.is_game_over(), live.is_game_over());
+    (live, resumed)
+}
+
+#[test]
+fn rocks_and_diamonds_match_golden_file() {
+    run_golden("rocks_and_diamonds");
+}
+
+#[test]
+fn enemies_match_golden_file() {
+    run_golden("enemies");
+}
+
+#[test]
+fn magic_wall_matches_golden_file() {
+    run_golden("magic_wall");
+}
+
+#[test]
+fn falling_rock_keeps_falling_after_resume() {
+    let level = "version = 1\n-\nWWWWW\nW r W\nW   W\nW   W\nW   W\nW P W\nWWWXW\n";
+    let mut grid: Grid = level.parse().unwrap();
+    for _ in 0..4 {
+        grid.step(Movement::Afk);
+    }
+    let mut resumed = resume(&grid);
+    for _ in 0..20 {
+        grid.step(Movement::Afk);
+        resumed.step(Movement::Afk);
+    }
+    assert!(grid.is_game_over());
+    assert!(resumed.is_game_over());
+}
+
+#[test]
+fn enemies_keep_their_heading_after_resume() {
+    let level =
+        "version = 1\n-\nWWWWWWWWW\nWP......W\nW.      W\nW.  q   W\nW.    B W\nWWWWWWWXW\n";
+    assert_resume_matches(level, &[Movement::Afk; 30], &[Movement::Afk; 80]);
+}
+
+#[test]
+fn pushing_player_keeps_pushing_after_resume() {
+    let level = "version = 1\n-\nWWWWWWWW\nWPr    W\nWWWWWWXW\n";
+    let mut before = vec![Movement::Afk; 60];
+    before.extend([Movement::MoveRight, Movement::Afk]);
+    assert_resume_matches(level, &before, &[Movement::MoveRight; 10]);
+}
+
+#[test]
+fn elapsed_time_survives_resume() {
+    let level = "version = 1\ncave_time = 2.0\n-\nWWWW\nWP W\nWWXW\n";
+    let (live, resumed) = assert_resume_matches(level, &[Movement::Afk; 30], &[Movement::Afk; 20]);
+    assert!((resumed.get_elapsed_time() - live.get_elapsed_time()).abs() < 1e-9);
+
+    let mut grid: Grid = level.parse().unwrap();
+    for _ in 0..45 {
+        grid.step(Movement::Afk);
+    }
+    assert!(grid.is_out_of_time());
+    assert!(resume(&grid).is_out_of_time());
+}
+
+#[test]
+fn lit_bomb_keeps_burning_after_resume() {
+    let level = "version = 1\n-\nWWWWWWWWW\nWPb     W\nWWWWWWWXW\n";
+    let mut before = vec![Movement::Afk; 90];
+    before.extend([Movement::MoveRight, Movement::Afk]);
+    before.extend([Movement::DropBomb, Movement::Afk]);
+
+    let (live, resumed) = assert_resume_matches(level, &before, &[Movement::Afk; 40]);
+    assert!(live.is_game_over());
+    assert!(resumed.is_game_over());
+
+    before.extend([Movement::MoveRight, Movement::Afk, Movement::MoveRight]);
+    let (live, resumed) = assert_resume_matches(level, &before, &[Movement::Afk; 40]);
+    assert!(live.get_tiles_with_entity::<Bomb>().is_empty());
+    assert!(resumed.get_tiles_with_entity::<Bomb>().is_empty());
+    assert!(!resumed.is_game_over());
+}
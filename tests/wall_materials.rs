@@ -0,0 +1,61 @@
+use boulderdash::game::{
+    enums::{action_type::ActionType, movement::Movement},
+    grid::Grid,
+};
+
+fn run_cave(map: &[&str], scans: usize) -> Vec<String> {
+    let mut grid: Grid = format!("version = 1\n-\n{}\n", map.join("\n"))
+        .parse()
+        .expect("Test cave should parse");
+    for _ in 0..scans * 2 {
+        grid.step(Movement::Afk);
+    }
+    grid.to_level_definition()
+        .get_map()
+        .iter()
+        .map(|row| row.iter().collect())
+        .collect()
+}
+
+#[test]
+fn rocks_and_diamonds_roll_off_brick_but_not_steel() {
+    for object in ["r", "d"] {
+        let row = format!("WP  {}  {} W", object, object);
+        let cave = [
+            "WWWWWWWWWW",
+            row.as_str(),
+            "W   w  W W",
+            "W        W",
+            "W       XW",
+            "WWWWWWWWWW",
+        ];
+        let map = run_cave(&cave, 10);
+        assert_eq!(
+            map[1],
+            "WP     r W".replace('r', object),
+            "{} on steel",
+            object
+        );
+        assert_eq!(
+            map[4],
+            "W  r    XW".replace('r', object),
+            "{} off brick",
+            object
+        );
+    }
+}
+
+#[test]
+fn explosions_destroy_brick_but_not_steel() {
+    let mut grid: Grid = "version = 1\n-\nWWWWWWWW\nWP  wW W\nW   W  W\nW   w XW\nWWWWWWWW\n"
+        .parse()
+        .unwrap();
+    let actions = grid.get_explosion_actions((5, 2), ActionType::ExplodeToSpace);
+    grid.apply_actions(actions);
+    let level = grid.to_level_definition();
+    assert_eq!(level.get_char(4, 1), Some(' '));
+    assert_eq!(level.get_char(4, 3), Some(' '));
+    assert_eq!(level.get_char(5, 1), Some('W'));
+    assert_eq!(level.get_char(4, 2), Some('W'));
+    assert_eq!(level.get_char(6, 3), Some('X'));
+}